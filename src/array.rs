@@ -0,0 +1,31 @@
+use crate::WantExtract;
+use std::convert::TryInto;
+
+/// Get what you want out of every element, or the first rejection
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let all_ok: [Result<i32, &str>; 3] = [Ok(1), Ok(2), Ok(3)];
+/// assert_eq!(try_array_wanted(all_ok), Ok([1, 2, 3]));
+///
+/// let middle_err: [Result<i32, &str>; 3] = [Ok(1), Err("bad"), Err("worse")];
+/// assert_eq!(try_array_wanted(middle_err), Err("bad"));
+///
+/// assert_eq!(try_array_wanted([Some('a'), Some('b')]), Ok(['a', 'b']));
+/// ```
+pub fn try_array_wanted<W, const N: usize>(array: [W; N]) -> Result<[W::Output; N], W::Rejected>
+where
+    W: WantExtract,
+{
+    let mut values = Vec::with_capacity(N);
+    for item in IntoIterator::into_iter(array) {
+        values.push(item.ok_or_reject()?);
+    }
+    match values.try_into() {
+        Ok(values) => Ok(values),
+        Err(_) => unreachable!("an array of {} yields {} values", N, N),
+    }
+}
@@ -0,0 +1,53 @@
+/// Make a `Result` that is what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let result = want_ok::<_, ()>(42);
+/// assert_eq!(result, Ok(42));
+/// ```
+pub fn want_ok<T, E>(value: T) -> Result<T, E> {
+    Ok(value)
+}
+
+/// Make an `Option` that is what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(want_some(42), Some(42));
+/// ```
+pub fn want_some<T>(value: T) -> Option<T> {
+    Some(value)
+}
+
+/// Make a `Result` that is not what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let result = want_err::<i32, _>("invalid");
+/// assert_eq!(result, Err("invalid"));
+/// ```
+pub fn want_err<T, E>(error: E) -> Result<T, E> {
+    Err(error)
+}
+
+/// Make an `Option` that is not what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(want_none::<i32>(), None);
+/// ```
+pub fn want_none<T>() -> Option<T> {
+    None
+}
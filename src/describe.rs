@@ -0,0 +1,73 @@
+use crate::WhatIwant;
+use std::fmt;
+
+#[doc(hidden)]
+pub fn reject_reason_of<W: WhatIwant + ?Sized>(want: &W) -> Option<String> {
+    if want.is_i_want() {
+        None
+    } else {
+        want.reject_reason()
+    }
+}
+
+#[doc(hidden)]
+pub struct DescribeRejected<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait DescribeUnit {
+    fn describe(&self) -> String;
+}
+
+// A rejected `()`, as from an `Option`, has nothing to show, so it's
+// described like `WantAnyhow` does. Two references further out than
+// `DescribeDebug`, so it's found first
+impl DescribeUnit for &&DescribeRejected<'_, ()> {
+    fn describe(&self) -> String {
+        "value not present".to_string()
+    }
+}
+
+#[doc(hidden)]
+pub trait DescribeDisplay {
+    fn describe(&self) -> String;
+}
+
+// Implemented one reference further out than `DescribeDebug`, so method
+// lookup finds this one before it when it applies
+impl<T: fmt::Display + ?Sized> DescribeDisplay for &DescribeRejected<'_, T> {
+    fn describe(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[doc(hidden)]
+pub trait DescribeDebug {
+    fn describe(&self) -> String;
+}
+
+impl<T: fmt::Debug + ?Sized> DescribeDebug for DescribeRejected<'_, T> {
+    fn describe(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Describe what was rejected for a log: its reject reason if there is one,
+/// else `value not present` for `()`, else its `Display` if it has one, else
+/// its `Debug`
+///
+/// `Display` is only picked where the rejected type is known to have it, so
+/// in generic code bounded by `Debug` it's always `Debug`
+macro_rules! __describe_rejected {
+    ($reason: expr, $rejected: expr) => {
+        match $reason {
+            Some(reason) => reason,
+            None => {
+                #[allow(unused_imports)]
+                use $crate::{DescribeDebug as _, DescribeDisplay as _, DescribeUnit as _};
+                (&&&$crate::DescribeRejected(&$rejected)).describe()
+            }
+        }
+    };
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt;
+
+/// What was rejected, with the context attached to it on the way up
+///
+/// It's meant to be built in the closure of
+/// [`context_unwrap_or_return!`](crate::context_unwrap_or_return), each layer
+/// adding its context with [`context`](WhatIwantError::context). It displays
+/// as the contexts from the outermost in, then what was rejected, so the whole
+/// chain is in its message and it has no `source`
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let error = WhatIwantError::new("invalid digit")
+///     .context("while parsing")
+///     .context("while loading");
+/// assert_eq!(error.to_string(), "while loading: while parsing: invalid digit");
+/// assert_eq!(error.contexts().collect::<Vec<_>>(), ["while loading", "while parsing"]);
+/// assert_eq!(error.rejected(), &"invalid digit");
+/// assert_eq!(error.into_rejected(), "invalid digit");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhatIwantError<E> {
+    rejected: E,
+    contexts: Vec<String>,
+}
+
+impl<E> WhatIwantError<E> {
+    pub fn new(rejected: E) -> Self {
+        WhatIwantError {
+            rejected,
+            contexts: Vec::new(),
+        }
+    }
+
+    /// Attach context around what is already attached
+    pub fn context<C: Into<String>>(mut self, context: C) -> Self {
+        self.contexts.push(context.into());
+        self
+    }
+
+    /// The contexts, from the outermost in
+    pub fn contexts(&self) -> impl Iterator<Item = &str> {
+        self.contexts.iter().rev().map(String::as_str)
+    }
+
+    pub fn rejected(&self) -> &E {
+        &self.rejected
+    }
+
+    pub fn into_rejected(self) -> E {
+        self.rejected
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for WhatIwantError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for context in self.contexts() {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "{}", self.rejected)
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for WhatIwantError<E> {}
@@ -0,0 +1,44 @@
+use std::sync::RwLock;
+
+const DEFAULT_EXPECT_MESSAGE: &str = "not what I want: {reason}";
+
+static EXPECT_MESSAGE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the panic message template used by [`expect_i_want!`](crate::expect_i_want)
+/// when no message is given
+///
+/// `{reason}` in the template is replaced with the
+/// [`reject_reason`](crate::WhatIwant::reject_reason), or what was rejected:
+/// `value not present` for an `Option`, the error of a `Result`. The template
+/// is `not what I want: {reason}` until it's set
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// set_default_expect_message("value required: {reason}");
+///
+/// let panic = std::panic::catch_unwind(|| expect_i_want!(None::<i32>)).unwrap_err();
+/// assert_eq!(
+///     panic.downcast_ref::<String>().unwrap(),
+///     "value required: value not present"
+/// );
+/// ```
+pub fn set_default_expect_message<S: Into<String>>(template: S) {
+    let mut message = match EXPECT_MESSAGE.write() {
+        Ok(message) => message,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    *message = Some(template.into());
+}
+
+#[doc(hidden)]
+pub fn default_expect_message(reason: &str) -> String {
+    let message = match EXPECT_MESSAGE.read() {
+        Ok(message) => message,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let template = message.as_deref().unwrap_or(DEFAULT_EXPECT_MESSAGE);
+    template.replace("{reason}", reason)
+}
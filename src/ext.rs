@@ -0,0 +1,409 @@
+use crate::{WantExtract, WantExtractRef};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Methods for everything you can get what you want out of
+pub trait WhatIwantExt: WantExtract + Sized {
+    /// Get `Some` of what you want, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let items = vec!["1", "oops", "3"];
+    /// let wanted: Vec<i32> = items
+    ///     .into_iter()
+    ///     .filter_map(|s| s.parse::<i32>().i_want_opt())
+    ///     .collect();
+    /// assert_eq!(wanted, vec![1, 3]);
+    /// ```
+    ///
+    /// It composes with parallel iterators, which have no `continue`
+    ///
+    /// ```ignore
+    /// use rayon::prelude::*;
+    /// use what_i_want::*;
+    ///
+    /// let wanted: Vec<i32> = items
+    ///     .par_iter()
+    ///     .filter_map(|s| s.parse::<i32>().i_want_opt())
+    ///     .collect();
+    /// ```
+    fn i_want_opt(self) -> Option<Self::Output> {
+        self.ok_or_reject().ok()
+    }
+
+    /// Get what you want, or log what was rejected and get the default value
+    ///
+    /// It works for every type, so what was rejected is formatted with `Debug`;
+    /// [`ok_or_log_default!`](crate::ok_or_log_default) logs a `Result`'s error
+    /// with `Display` instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use what_i_want::*;
+    ///
+    /// static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    ///
+    /// struct TestLogger;
+    ///
+    /// impl log::Log for TestLogger {
+    ///     fn enabled(&self, _: &log::Metadata) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn log(&self, record: &log::Record) {
+    ///         LOGGED.lock().unwrap().push(record.args().to_string());
+    ///     }
+    ///
+    ///     fn flush(&self) {}
+    /// }
+    ///
+    /// log::set_logger(&TestLogger).unwrap();
+    /// log::set_max_level(log::LevelFilter::Error);
+    ///
+    /// assert_eq!("2".parse::<i32>().ok_or_log_default(), 2);
+    /// assert!(LOGGED.lock().unwrap().is_empty());
+    ///
+    /// #[derive(Debug)]
+    /// enum MyErr {
+    ///     Missing,
+    /// }
+    ///
+    /// assert_eq!("oops".parse::<i32>().ok_or_log_default(), 0);
+    /// assert_eq!(None::<String>.ok_or_log_default(), "");
+    /// assert_eq!(Err::<i32, _>(MyErr::Missing).ok_or_log_default(), 0);
+    /// assert_eq!(
+    ///     *LOGGED.lock().unwrap(),
+    ///     [
+    ///         "not what I want: ParseIntError { kind: InvalidDigit }",
+    ///         "not what I want: ()",
+    ///         "not what I want: Missing",
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// The [`reject_reason`](crate::WhatIwant::reject_reason) is logged when
+    /// there is one
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use what_i_want::*;
+    ///
+    /// static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    ///
+    /// struct TestLogger;
+    ///
+    /// impl log::Log for TestLogger {
+    ///     fn enabled(&self, _: &log::Metadata) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn log(&self, record: &log::Record) {
+    ///         LOGGED.lock().unwrap().push(record.args().to_string());
+    ///     }
+    ///
+    ///     fn flush(&self) {}
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Age(i32);
+    ///
+    /// impl WhatIwant for Age {
+    ///     fn is_i_want(&self) -> bool {
+    ///         self.0 >= 0
+    ///     }
+    ///
+    ///     fn reject_reason(&self) -> Option<String> {
+    ///         Some(format!("age {} is negative", self.0))
+    ///     }
+    /// }
+    ///
+    /// impl WantExtract for Age {
+    ///     type Output = i32;
+    ///     type Rejected = i32;
+    ///
+    ///     fn ok_or_reject(self) -> Result<i32, i32> {
+    ///         if self.is_i_want() {
+    ///             Ok(self.0)
+    ///         } else {
+    ///             Err(self.0)
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// log::set_logger(&TestLogger).unwrap();
+    /// log::set_max_level(log::LevelFilter::Error);
+    ///
+    /// assert_eq!(Age(-3).ok_or_log_default(), 0);
+    /// assert_eq!(LOGGED.lock().unwrap()[0], "not what I want: age -3 is negative");
+    /// ```
+    #[cfg(feature = "log")]
+    fn ok_or_log_default(self) -> Self::Output
+    where
+        Self::Output: Default,
+        Self::Rejected: std::fmt::Debug,
+    {
+        let reason = crate::reject_reason_of(&self);
+        match self.ok_or_reject() {
+            Ok(value) => value,
+            Err(rejected) => {
+                log::error!(
+                    "not what I want: {}",
+                    crate::__describe_rejected!(reason, rejected)
+                );
+                Default::default()
+            }
+        }
+    }
+
+    /// Get what you want, or hand what was rejected to the reporter and get `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let mut reports = Vec::new();
+    /// assert_eq!("2".parse::<i32>().unwrap_or_report(|e| reports.push(e)), Some(2));
+    /// assert!(reports.is_empty());
+    /// assert_eq!("oops".parse::<i32>().unwrap_or_report(|e| reports.push(e)), None);
+    /// assert_eq!(reports.len(), 1);
+    ///
+    /// let mut missing = 0;
+    /// assert_eq!(None::<i32>.unwrap_or_report(|()| missing += 1), None);
+    /// assert_eq!(missing, 1);
+    /// ```
+    fn unwrap_or_report<F>(self, reporter: F) -> Option<Self::Output>
+    where
+        F: FnOnce(Self::Rejected),
+    {
+        match self.ok_or_reject() {
+            Ok(value) => Some(value),
+            Err(rejected) => {
+                reporter(rejected);
+                None
+            }
+        }
+    }
+
+    /// Get what you want, or count the rejection on `counter` and get `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// use what_i_want::*;
+    ///
+    /// let rejected = Arc::new(AtomicUsize::new(0));
+    /// let handles: Vec<_> = (0..4)
+    ///     .map(|_| {
+    ///         let rejected = Arc::clone(&rejected);
+    ///         thread::spawn(move || {
+    ///             ["1", "x", "2", "y", "z"]
+    ///                 .iter()
+    ///                 .filter_map(|s| s.parse::<i32>().unwrap_or_count(&rejected))
+    ///                 .sum::<i32>()
+    ///         })
+    ///     })
+    ///     .collect();
+    /// for handle in handles {
+    ///     assert_eq!(handle.join().unwrap(), 3);
+    /// }
+    /// assert_eq!(rejected.load(Ordering::Relaxed), 12);
+    /// ```
+    fn unwrap_or_count(self, counter: &AtomicUsize) -> Option<Self::Output> {
+        match self.ok_or_reject() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                counter.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Map what you want with the closure, keeping what was rejected as it is
+    ///
+    /// It's named apart from [`IteratorExt::map_wanted`](crate::IteratorExt::map_wanted),
+    /// which maps an iterator's items to what you want out of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// assert_eq!(Some(2).map_want_result(|n| n * 10), Ok(20));
+    /// assert_eq!(None::<i32>.map_want_result(|n| n * 10), Err(()));
+    /// assert_eq!("2".parse::<i32>().map_want_result(|n| n.to_string()), Ok("2".to_string()));
+    /// assert!("oops".parse::<i32>().map_want_result(|n| n.to_string()).is_err());
+    ///
+    /// enum Reply {
+    ///     Success(String),
+    ///     Failed(u16),
+    /// }
+    ///
+    /// impl WhatIwant for Reply {
+    ///     fn is_i_want(&self) -> bool {
+    ///         matches!(self, Reply::Success(_))
+    ///     }
+    /// }
+    ///
+    /// impl WantExtract for Reply {
+    ///     type Output = String;
+    ///     type Rejected = u16;
+    ///
+    ///     fn ok_or_reject(self) -> Result<String, u16> {
+    ///         match self {
+    ///             Reply::Success(body) => Ok(body),
+    ///             Reply::Failed(code) => Err(code),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Reply::Success("hello".into()).map_want_result(|body| body.len()), Ok(5));
+    /// assert_eq!(Reply::Failed(404).map_want_result(|body| body.len()), Err(404));
+    /// ```
+    fn map_want_result<U, F>(self, f: F) -> Result<U, Self::Rejected>
+    where
+        F: FnOnce(Self::Output) -> U,
+    {
+        self.ok_or_reject().map(f)
+    }
+
+    /// Get what you want, or recover it from what was rejected with the closure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let port = "http".parse::<u16>().recover_with(|_| 80);
+    /// assert_eq!(port, 80);
+    ///
+    /// let code = Err::<u16, _>("404 Not Found").recover_with(|e| e[..3].parse().unwrap());
+    /// assert_eq!(code, 404);
+    ///
+    /// assert_eq!(None::<String>.recover_with(|()| "anonymous".to_string()), "anonymous");
+    /// assert_eq!(Some(1).recover_with(|()| 0), 1);
+    /// ```
+    fn recover_with<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Rejected) -> Self::Output,
+    {
+        match self.ok_or_reject() {
+            Ok(value) => value,
+            Err(rejected) => f(rejected),
+        }
+    }
+
+    /// Keep it if it's what you want, otherwise take the alternative instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let from_flag: Option<u16> = None;
+    /// let from_env: Option<u16> = None;
+    /// let port = from_flag.or_try(from_env).or_try(Some(8080));
+    /// assert_eq!(port, Some(8080));
+    ///
+    /// let parsed = "x".parse::<u16>().or_try("".parse()).or_try("80".parse());
+    /// assert_eq!(parsed, Ok(80));
+    ///
+    /// let parsed = "x".parse::<u16>().or_try("".parse()).or_try("y".parse());
+    /// assert!(parsed.is_err());
+    /// ```
+    fn or_try(self, alternative: Self) -> Self {
+        if self.is_i_want() {
+            self
+        } else {
+            alternative
+        }
+    }
+
+    /// Look at what you want, without taking it out, and keep going
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let mut seen = Vec::new();
+    /// let parsed = "2".parse::<i32>().tap_wanted(|n| seen.push(*n));
+    /// assert_eq!(parsed, Ok(2));
+    ///
+    /// let parsed = "oops".parse::<i32>().tap_wanted(|n| seen.push(*n));
+    /// assert!(parsed.is_err());
+    ///
+    /// let name = Some(String::from("admin")).tap_wanted(|name| seen.push(name.len() as i32));
+    /// assert_eq!(name.as_deref(), Some("admin"));
+    /// assert_eq!(seen, vec![2, 5]);
+    /// ```
+    fn tap_wanted<F>(self, f: F) -> Self
+    where
+        Self: WantExtractRef,
+        F: FnOnce(&Self::Output),
+    {
+        if let Some(value) = self.wanted_ref() {
+            f(value);
+        }
+        self
+    }
+}
+
+impl<T: WantExtract> WhatIwantExt for T {}
+
+/// Swap the nesting of what you want, like `Option::transpose` and
+/// `Result::transpose`
+///
+/// An `Option<Result<T, E>>` becomes a `Result<Option<T>, E>` so it can be
+/// `?`-ed, and the other way around
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(Some(Ok::<i32, &str>(1)).transpose_wanted(), Ok(Some(1)));
+/// assert_eq!(Some(Err::<i32, &str>("bad")).transpose_wanted(), Err("bad"));
+/// assert_eq!(None::<Result<i32, &str>>.transpose_wanted(), Ok(None));
+///
+/// assert_eq!(Ok::<_, &str>(Some(1)).transpose_wanted(), Some(Ok(1)));
+/// assert_eq!(Err::<Option<i32>, _>("bad").transpose_wanted(), Some(Err("bad")));
+/// assert_eq!(Ok::<Option<i32>, &str>(None).transpose_wanted(), None);
+///
+/// fn port(env: Option<&str>) -> Result<u16, std::num::ParseIntError> {
+///     let port = env.map(str::parse::<u16>).transpose_wanted()?;
+///     Ok(port.unwrap_or(80))
+/// }
+///
+/// assert_eq!(port(None), Ok(80));
+/// assert_eq!(port(Some("8080")), Ok(8080));
+/// assert!(port(Some("http")).is_err());
+/// ```
+pub trait TransposeWanted {
+    type Transposed;
+
+    fn transpose_wanted(self) -> Self::Transposed;
+}
+
+impl<T, E> TransposeWanted for Option<Result<T, E>> {
+    type Transposed = Result<Option<T>, E>;
+
+    fn transpose_wanted(self) -> Result<Option<T>, E> {
+        self.transpose()
+    }
+}
+
+impl<T, E> TransposeWanted for Result<Option<T>, E> {
+    type Transposed = Option<Result<T, E>>;
+
+    fn transpose_wanted(self) -> Option<Result<T, E>> {
+        self.transpose()
+    }
+}
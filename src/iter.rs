@@ -0,0 +1,280 @@
+use crate::{WantExtract, WhatIwant};
+use std::fmt;
+use std::iter::{Filter, FilterMap, Flatten, FromIterator, Peekable};
+
+/// Iterator returned by [`IteratorExt::filter_wanted`]
+pub type FilterWanted<I> = Filter<I, fn(&<I as Iterator>::Item) -> bool>;
+
+/// Iterator returned by [`IteratorExt::map_wanted`]
+pub type MapWanted<I> = FilterMap<
+    I,
+    fn(<I as Iterator>::Item) -> Option<<<I as Iterator>::Item as WantExtract>::Output>,
+>;
+
+/// Iterator returned by [`IteratorExt::flatten_wanted`]
+pub type FlattenWanted<I> = Flatten<MapWanted<I>>;
+
+/// Iterator returned by [`IteratorExt::filter_map_wanted`]
+#[derive(Debug, Clone)]
+pub struct FilterMapWanted<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, W> Iterator for FilterMapWanted<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> W,
+    W: WantExtract,
+{
+    type Item = W::Output;
+
+    fn next(&mut self) -> Option<W::Output> {
+        for item in &mut self.iter {
+            if let Ok(value) = (self.f)(item).ok_or_reject() {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Iterator returned by [`IteratorExt::group_runs_wanted`]
+pub struct GroupRunsWanted<I: Iterator> {
+    iter: Peekable<I>,
+}
+
+impl<I> fmt::Debug for GroupRunsWanted<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupRunsWanted")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> Clone for GroupRunsWanted<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        GroupRunsWanted {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I> Iterator for GroupRunsWanted<I>
+where
+    I: Iterator,
+    I::Item: WhatIwant,
+{
+    type Item = (bool, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<(bool, Vec<I::Item>)> {
+        let first = self.iter.next()?;
+        let wanted = first.is_i_want();
+        let mut run = vec![first];
+        while let Some(item) = self.iter.next_if(|item| item.is_i_want() == wanted) {
+            run.push(item);
+        }
+        Some((wanted, run))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+/// Iterate over what you want
+pub trait IteratorExt: Iterator + Sized {
+    /// Keep only the items that are what you want, as they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let items = vec![Some(1), None, Some(3)];
+    /// let wanted: Vec<Option<i32>> = items.into_iter().filter_wanted().collect();
+    /// assert_eq!(wanted, vec![Some(1), Some(3)]);
+    /// ```
+    fn filter_wanted(self) -> FilterWanted<Self>
+    where
+        Self::Item: WhatIwant,
+    {
+        self.filter(|item| item.is_i_want())
+    }
+
+    /// Keep only the items that are what you want, and get what you want out of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let items = vec!["1", "oops", "3"];
+    /// let wanted: Vec<i32> = items.into_iter().map(str::parse::<i32>).map_wanted().collect();
+    /// assert_eq!(wanted, vec![1, 3]);
+    /// ```
+    fn map_wanted(self) -> MapWanted<Self>
+    where
+        Self::Item: WantExtract,
+    {
+        self.filter_map(|item| item.ok_or_reject().ok())
+    }
+
+    /// Map each item with the closure, keeping only what you want out of the results
+    ///
+    /// Parallel iterators such as rayon's have no `continue`; use
+    /// [`WhatIwantExt::i_want_opt`](crate::WhatIwantExt::i_want_opt) with their
+    /// `filter_map` instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let items = vec!["1", "oops", "3"];
+    /// let wanted: Vec<i32> = items.into_iter().filter_map_wanted(str::parse::<i32>).collect();
+    /// assert_eq!(wanted, vec![1, 3]);
+    /// ```
+    fn filter_map_wanted<F, W>(self, f: F) -> FilterMapWanted<Self, F>
+    where
+        F: FnMut(Self::Item) -> W,
+        W: WantExtract,
+    {
+        FilterMapWanted { iter: self, f }
+    }
+
+    /// Collect only what you want out of the items
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use what_i_want::*;
+    ///
+    /// let items = vec![Some(1), None, Some(3), Some(1)];
+    /// let wanted = items.iter().copied().collect_wanted::<Vec<_>>();
+    /// assert_eq!(wanted, vec![1, 3, 1]);
+    ///
+    /// let unique: HashSet<i32> = items.into_iter().collect_wanted();
+    /// assert_eq!(unique, [1, 3].iter().copied().collect());
+    /// ```
+    fn collect_wanted<C>(self) -> C
+    where
+        Self::Item: WantExtract,
+        C: FromIterator<<Self::Item as WantExtract>::Output>,
+    {
+        self.map_wanted().collect()
+    }
+
+    /// Flatten what you want out of the items, dropping the rejected ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let pages: Vec<Result<Vec<i32>, &str>> =
+    ///     vec![Ok(vec![1, 2]), Err("timeout"), Ok(vec![]), Ok(vec![3])];
+    /// let rows: Vec<i32> = pages.into_iter().flatten_wanted().collect();
+    /// assert_eq!(rows, vec![1, 2, 3]);
+    ///
+    /// let words: String = vec![Some("ab"), None, Some("c")]
+    ///     .into_iter()
+    ///     .map(|s| s.map(str::chars))
+    ///     .flatten_wanted()
+    ///     .collect();
+    /// assert_eq!(words, "abc");
+    /// ```
+    fn flatten_wanted(self) -> FlattenWanted<Self>
+    where
+        Self::Item: WantExtract,
+        <Self::Item as WantExtract>::Output: IntoIterator,
+    {
+        self.map_wanted().flatten()
+    }
+
+    /// Group the consecutive items into runs of what you want and runs of what
+    /// you don't, as `(wanted, items)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// let records = vec!["1", "2", "x", "y", "3", "z", "4", "5", "6"];
+    /// let runs: Vec<(bool, Vec<&str>)> = records
+    ///     .into_iter()
+    ///     .map(|r| r.parse::<i32>().map(|_| r))
+    ///     .group_runs_wanted()
+    ///     .map(|(wanted, run)| (wanted, run.into_iter().map(|r| r.unwrap_or("?")).collect()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     runs,
+    ///     vec![
+    ///         (true, vec!["1", "2"]),
+    ///         (false, vec!["?", "?"]),
+    ///         (true, vec!["3"]),
+    ///         (false, vec!["?"]),
+    ///         (true, vec!["4", "5", "6"]),
+    ///     ]
+    /// );
+    ///
+    /// let report: Vec<String> = vec![Some(1), Some(2), None]
+    ///     .into_iter()
+    ///     .group_runs_wanted()
+    ///     .map(|(wanted, run)| format!("{} {}", if wanted { "processed" } else { "bad" }, run.len()))
+    ///     .collect();
+    /// assert_eq!(report, vec!["processed 2", "bad 1"]);
+    /// assert_eq!(std::iter::empty::<Option<i32>>().group_runs_wanted().count(), 0);
+    /// ```
+    fn group_runs_wanted(self) -> GroupRunsWanted<Self>
+    where
+        Self::Item: WhatIwant,
+    {
+        GroupRunsWanted {
+            iter: self.peekable(),
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Split the results into what you want and the errors, keeping their order
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let results = vec![Ok(1), Err("bad"), Ok(2), Err("worse"), Ok(3)];
+/// let (wanted, errors) = partition_wanted_results(results);
+/// assert_eq!(wanted, vec![1, 2, 3]);
+/// assert_eq!(errors, vec!["bad", "worse"]);
+/// ```
+pub fn partition_wanted_results<I, T, E>(iter: I) -> (Vec<T>, Vec<E>)
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    let mut wanted = Vec::new();
+    let mut errors = Vec::new();
+    for result in iter {
+        match result {
+            Ok(value) => wanted.push(value),
+            Err(e) => errors.push(e),
+        }
+    }
+    (wanted, errors)
+}
@@ -58,14 +58,83 @@
 //!     MultipartData { ... }
 //! }
 //! ```
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+
+mod array;
+mod constructors;
+mod describe;
+mod error;
+mod expect;
+mod ext;
+mod iter;
+mod severity;
+#[cfg(feature = "futures")]
+mod stream;
+mod throttle;
+#[cfg(feature = "nightly")]
+mod try_want;
+#[cfg(feature = "anyhow")]
+mod want_anyhow;
+#[cfg(feature = "either")]
+mod want_either;
+mod wrappers;
+
+pub use array::try_array_wanted;
+pub use constructors::{want_err, want_none, want_ok, want_some};
+#[doc(hidden)]
+pub use describe::{
+    reject_reason_of, DescribeDebug, DescribeDisplay, DescribeRejected, DescribeUnit,
+};
+pub use error::WhatIwantError;
+#[doc(hidden)]
+pub use expect::default_expect_message;
+pub use expect::set_default_expect_message;
+pub use ext::{TransposeWanted, WhatIwantExt};
+pub use iter::{
+    partition_wanted_results, FilterMapWanted, FilterWanted, FlattenWanted, GroupRunsWanted,
+    IteratorExt, MapWanted,
+};
+pub use severity::{HasSeverity, Severity, SeverityWant};
+#[doc(hidden)]
+pub use throttle::Throttle;
+#[cfg(feature = "nightly")]
+pub use try_want::TryWant;
+/// An alias of [`want_and`](fn@want_and), for reading as zipping two of them
+/// into one
+pub use wrappers::want_and as zip_wanted;
+pub use wrappers::{
+    not_want, want_and, want_or, AsyncStreamItem, CurrentState, Negated, NoError, NonDefaultOption,
+    OnBreak, RecoverableUtf8, TryLockWant, WantAnd, WantBufIo, WantFpCategory, WantJoined, WantOr,
+    WantOrdering, WantParsed, WantRangeCheck, WantWriter,
+};
+
+#[cfg(feature = "futures")]
+pub use stream::{SkipRejected, WantStreamExt};
+#[cfg(feature = "anyhow")]
+pub use want_anyhow::WantAnyhow;
+#[cfg(feature = "either")]
+pub use want_either::{split_either, want_left, WantLeft};
+
+#[cfg(feature = "chrono")]
+#[doc(hidden)]
+pub use chrono;
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub use log;
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
 
 /// Implement `WhatIwant` and let us know what you want
 ///
+/// `is_i_want` is enough for [`require!`], but the `unwrap_or_*` macros get
+/// what you want out with [`WantExtract`], so implement that too. For a
+/// newtype, [`impl_want_delegate!`] with `=> $inner` implements both
 ///
 /// # Examples
 ///
 /// ```ignore
-/// use what_i_want::WhatIwant;
+/// use what_i_want::*;
 ///
 ///
 /// impl<T, E> WhatIwant for Result<T, E> {
@@ -96,185 +165,5414 @@
 ///     }
 /// }
 ///
+/// // Needed by the unwrap_or_* macros
+/// impl WantExtract for LoginReply {
+///     type Output = ();
+///     type Rejected = i32;
+///
+///     fn ok_or_reject(self) -> Result<(), i32> {
+///         match self {
+///             LoginReply::Success => Ok(()),
+///             LoginReply::Failed(code) => Err(code),
+///         }
+///     }
+/// }
+///
+/// // A newtype delegating both to what it holds
+/// struct UserId(Option<u64>);
+/// impl_want_delegate!(UserId, |self| self.0 => Option<u64>);
+///
+/// ```
+///
+/// With both implemented, your type works with every macro
+///
+/// ```
+/// use what_i_want::*;
+///
+/// enum LoginReply {
+///     Success,
+///     Failed(i32),
+/// }
+///
+/// impl WhatIwant for LoginReply {
+///     fn is_i_want(&self) -> bool {
+///         matches!(self, LoginReply::Success)
+///     }
+/// }
+///
+/// impl WantExtract for LoginReply {
+///     type Output = ();
+///     type Rejected = i32;
+///
+///     fn ok_or_reject(self) -> Result<(), i32> {
+///         match self {
+///             LoginReply::Success => Ok(()),
+///             LoginReply::Failed(code) => Err(code),
+///         }
+///     }
+/// }
+///
+/// struct UserId(Option<u64>);
+/// impl_want_delegate!(UserId, |self| self.0 => Option<u64>);
+///
+/// fn handle(reply: LoginReply, id: UserId) -> Option<u64> {
+///     unwrap_or_val!(reply, None);
+///     Some(unwrap_or_val!(id, None))
+/// }
+///
+/// assert_eq!(handle(LoginReply::Success, UserId(Some(7))), Some(7));
+/// assert_eq!(handle(LoginReply::Failed(403), UserId(Some(7))), None);
+/// assert_eq!(handle(LoginReply::Success, UserId(None)), None);
 /// ```
 pub trait WhatIwant {
     fn is_i_want(&self) -> bool;
+
+    /// Why it's not what you want, shown by the logging methods
+    ///
+    /// `Result` and `Option` have no reason of their own; the logging methods
+    /// show what was rejected instead
+    fn reject_reason(&self) -> Option<String> {
+        None
+    }
 }
 
+/// An `Ok` is what you want
+///
+/// This covers the `Result` aliases too, such as `TryLockResult`, where
+/// `Ok(guard)` means the lock was acquired
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+/// use std::thread;
+/// use what_i_want::*;
+///
+/// let counter = Mutex::new(0);
+/// let try_increment = || {
+///     let mut skipped = 0;
+///     for _ in 0..3 {
+///         let mut guard = unwrap_or_do!(counter.try_lock(), {
+///             skipped += 1;
+///             continue;
+///         });
+///         *guard += 1;
+///     }
+///     skipped
+/// };
+///
+/// let held = counter.lock().unwrap();
+/// let skipped = thread::scope(|s| s.spawn(try_increment).join().unwrap());
+/// assert_eq!(skipped, 3);
+/// drop(held);
+///
+/// let skipped = thread::scope(|s| s.spawn(try_increment).join().unwrap());
+/// assert_eq!(skipped, 0);
+/// assert_eq!(*counter.lock().unwrap(), 3);
+/// ```
 impl<T, E> WhatIwant for Result<T, E> {
     fn is_i_want(&self) -> bool {
         self.is_ok()
     }
 }
 
+/// A `Result` with nothing in its `Ok`, what you want is that it succeeded
+///
+/// It's covered by the `Result` impls like any other `Result`, this only names
+/// the pattern; see [`require_ok_unit!`] for requiring it
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn validate(name: &str) -> WantResult0<&'static str> {
+///     if name.is_empty() {
+///         Err("empty name")
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// assert!(validate("admin").is_i_want());
+/// assert!(!validate("").is_i_want());
+/// ```
+pub type WantResult0<E> = Result<(), E>;
+
 impl<T> WhatIwant for Option<T> {
     fn is_i_want(&self) -> bool {
         self.is_some()
     }
 }
 
-#[macro_export]
-/// If it's not what you want, then do what you want
+/// Implement `WantExtract` and let us get what you want out of it
 ///
 /// # Examples
 ///
 /// ```
-/// use what_i_want::*;
+/// use what_i_want::{WantExtract, WhatIwant};
 ///
-/// let an_err: Result<Option<i32>, ()> = Ok(Some(1));
-/// let an_option: Option<Option<i32>> = Some(Some(1));
+/// enum LoginReply {
+///     Success(String),
+///     Failed(i32),
+/// }
 ///
-/// unwrap_or_do!(an_err, Some(0));
-/// unwrap_or_do!(an_option, Some(0));
+/// impl WhatIwant for LoginReply {
+///     fn is_i_want(&self) -> bool {
+///         matches!(self, LoginReply::Success(_))
+///     }
+/// }
 ///
-/// fn a_func() -> bool {
-///     let an_option = Some(true);
-///     unwrap_or_do!(an_option, return false)
+/// impl WantExtract for LoginReply {
+///     type Output = String;
+///     type Rejected = i32;
+///
+///     fn ok_or_reject(self) -> Result<String, i32> {
+///         match self {
+///             LoginReply::Success(token) => Ok(token),
+///             LoginReply::Failed(code) => Err(code),
+///         }
+///     }
 /// }
 ///
+/// assert_eq!(LoginReply::Success("token".into()).ok_or_reject(), Ok("token".into()));
+/// assert_eq!(LoginReply::Failed(403).ok_or_reject(), Err(403));
 /// ```
-macro_rules! unwrap_or_do {
-    ($exp: expr, $do: expr) => {
-        if $exp.is_i_want() {
-            $do
-        } else {
-            $exp.unwrap()
-        }
-    };
+pub trait WantExtract: WhatIwant {
+    type Output;
+    type Rejected;
+
+    fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected>;
 }
 
-#[macro_export]
-/// If it's not what you want, then do `continue`
+/// Implement `WantExtractRef` to let us look at what you want without taking it out
 ///
 /// # Examples
 ///
-/// ```ignore
-/// use what_i_want::*;
+/// ```
+/// use what_i_want::WantExtractRef;
 ///
-/// async fn get_mutipart_data(mut mutipart_data: Multipart) -> MultipartData {
-///     while let Some(Ok(mut field)) = mutipart_data.next().await {
-///         let disposition = unwrap_or_continue!(field.headers().get(&header::CONTENT_DISPOSITION));
-///         let disposition_str = unwrap_or_continue!(disposition.to_str());
-///         let dis = unwrap_or_continue!(ContentDisposition::parse(disposition_str));
-///         let key = unwrap_or_continue!(dis.name);
-///         while let Some(Ok(chunk)) = field.next().await {
-///             ...
-///         }
-///     }
-///     MultipartData { ... }
-/// }
+/// assert_eq!(Some(1).wanted_ref(), Some(&1));
+/// assert_eq!(Err::<i32, ()>(()).wanted_ref(), None);
 /// ```
-macro_rules! unwrap_or_continue {
-    ($exp: expr) => {
-        unwrap_or_do!($exp, continue)
-    };
+pub trait WantExtractRef: WantExtract {
+    fn wanted_ref(&self) -> Option<&Self::Output>;
+}
+
+impl<T, E> WantExtract for Result<T, E> {
+    type Output = T;
+    type Rejected = E;
+
+    fn ok_or_reject(self) -> Result<T, E> {
+        self
+    }
+}
+
+impl<T, E> WantExtractRef for Result<T, E> {
+    fn wanted_ref(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+}
+
+impl<T> WantExtract for Option<T> {
+    type Output = T;
+    type Rejected = ();
+
+    fn ok_or_reject(self) -> Result<T, ()> {
+        self.ok_or(())
+    }
+}
+
+impl<T> WantExtractRef for Option<T> {
+    fn wanted_ref(&self) -> Option<&T> {
+        self.as_ref()
+    }
 }
 
 #[macro_export]
-/// If it's not what you want, then do `return ()`
+/// Implement `WhatIwant` for smart pointers, delegating to what they point to
 ///
-/// # Examples
+/// A blanket impl over every `Deref` type would overlap the other impls, so
+/// list the pointer types instead. Each must be generic over one type `T` and
+/// `Deref<Target = T>`. `Box`, `Rc` and `Arc` are implemented with it
+///
+/// Only `WhatIwant` is generated, because what you want can't be moved out of
+/// an arbitrary `Deref`. Your pointer then works with `is_i_want` and
+/// [`require!`], but the `unwrap_or_*` macros also need `WantExtract`, which
+/// you implement yourself where it's sound. `Box` gets it by moving out of the
+/// box, and `Rc` and `Arc` by taking what they hold, cloning it if it's shared
+///
+/// # Expansion
 ///
 /// ```ignore
-/// use what_i_want::*;
+/// impl_want_deref!($($ptr),+ $(,)?)
+/// // expands to
+/// $(
+///     impl<T: $crate::WhatIwant> $crate::WhatIwant for $ptr<T> {
+///         fn is_i_want(&self) -> bool {
+///             $crate::WhatIwant::is_i_want(&**self)
+///         }
 ///
-/// fn a_func(result: Result<i32, ()>) -> () {
-///     let unwrapped = unwrap_or_return!(result);
-///     // If ok, do something, else return
-///     ...
-/// }
+///         fn reject_reason(&self) -> Option<String> {
+///             $crate::WhatIwant::reject_reason(&**self)
+///         }
+///     }
+/// )+
 /// ```
-macro_rules! unwrap_or_return {
-    ($exp: expr) => {
-        unwrap_or_do!($exp, return)
-    };
-}
-
-#[macro_export]
-/// If it's not what you want, then do `return false`
 ///
 /// # Examples
 ///
-/// ```ignore
+/// ```
+/// use std::ops::Deref;
+/// use std::rc::Rc;
 /// use what_i_want::*;
 ///
-/// fn a_func(result: Result<i32, ()>) -> bool {
-///     let unwrapped = unwrap_or_false!(result);
-///     // If ok, do something, else return
-///     ...
+/// struct MyPointer<T>(T);
+///
+/// impl<T> Deref for MyPointer<T> {
+///     type Target = T;
+///
+///     fn deref(&self) -> &T {
+///         &self.0
+///     }
 /// }
+///
+/// impl_want_deref!(MyPointer);
+///
+/// assert!(MyPointer(Some(1)).is_i_want());
+/// assert!(!MyPointer(None::<i32>).is_i_want());
+/// assert!(Box::new(Ok::<i32, ()>(1)).is_i_want());
+/// assert!(!Rc::new(None::<i32>).is_i_want());
 /// ```
-macro_rules! unwrap_or_false {
-    ($exp: expr) => {
-        unwrap_or_do!($exp, return false)
+macro_rules! impl_want_deref {
+    ($($ptr: ident),+ $(,)?) => {
+        $(
+            impl<T: $crate::WhatIwant> $crate::WhatIwant for $ptr<T> {
+                fn is_i_want(&self) -> bool {
+                    $crate::WhatIwant::is_i_want(&**self)
+                }
+
+                fn reject_reason(&self) -> Option<String> {
+                    $crate::WhatIwant::reject_reason(&**self)
+                }
+            }
+        )+
     };
 }
 
 #[macro_export]
-/// If it's not what you want, then do `return true`
+/// Implement `WhatIwant` for a wrapper type, delegating to what the accessor
+/// gives, e.g. a field
 ///
-/// # Examples
+/// The accessor is written like a closure taking `self`, and what it gives
+/// is borrowed, so it can be a field or a dereference
+///
+/// Name the type the accessor gives after `=>` to implement `WantExtract` too,
+/// so the `unwrap_or_*` macros work with the wrapper. The accessor then also
+/// takes `self` by value, so it must move what it gives out of `self`
+///
+/// # Expansion
 ///
 /// ```ignore
-/// use what_i_want::*;
+/// impl_want_delegate!($ty, |$self| $access)
+/// // expands to
+/// impl $crate::WhatIwant for $ty {
+///     fn is_i_want(&$self) -> bool {
+///         $crate::WhatIwant::is_i_want(&$access)
+///     }
 ///
-/// fn a_func(result: Result<i32, ()>) -> bool {
-///     let unwrapped = unwrap_or_true!(result);
-///     // If ok, do something, else return
-///     ...
+///     fn reject_reason(&$self) -> Option<String> {
+///         $crate::WhatIwant::reject_reason(&$access)
+///     }
+/// }
+///
+/// impl_want_delegate!($ty, |$self| $access => $inner)
+/// // expands to
+/// impl_want_delegate!($ty, |$self| $access);
+///
+/// impl $crate::WantExtract for $ty {
+///     type Output = <$inner as $crate::WantExtract>::Output;
+///     type Rejected = <$inner as $crate::WantExtract>::Rejected;
+///
+///     fn ok_or_reject($self) -> Result<Self::Output, Self::Rejected> {
+///         $crate::WantExtract::ok_or_reject($access)
+///     }
 /// }
 /// ```
-macro_rules! unwrap_or_true {
-    ($exp: expr) => {
-        unwrap_or_do!($exp, return true)
-    };
-}
-
-#[macro_export]
-/// If it's not what you want, then do `return <defined return value>`
 ///
 /// # Examples
 ///
-/// ```ignore
+/// ```
+/// use std::ops::Deref;
 /// use what_i_want::*;
 ///
-/// fn a_func(result: Result<i32, ()>) -> bool {
-///     let unwrapped = unwrap_or_val!(result, false);
-///     // If ok, do something, else return
-///     ...
+/// struct UserId(Option<u64>);
+/// impl_want_delegate!(UserId, |self| self.0);
+///
+/// struct Config {
+///     port: Result<u16, String>,
+/// }
+/// impl_want_delegate!(Config, |self| self.port);
+///
+/// struct Checked(Option<char>);
+///
+/// impl Deref for Checked {
+///     type Target = Option<char>;
+///
+///     fn deref(&self) -> &Option<char> {
+///         &self.0
+///     }
 /// }
+/// impl_want_delegate!(Checked, |self| **self);
+///
+/// assert!(UserId(Some(1)).is_i_want());
+/// assert!(!UserId(None).is_i_want());
+/// assert!(Config { port: Ok(80) }.is_i_want());
+/// assert!(!Config { port: Err("no port".into()) }.is_i_want());
+/// assert!(Checked(Some('a')).is_i_want());
+/// assert!(!Checked(None).is_i_want());
 /// ```
-macro_rules! unwrap_or_val {
-    ($exp: expr, $val: expr) => {
-        unwrap_or_do!($exp, return $val)
-    };
-}
-
-#[macro_export]
-/// Execute if the condition is true, otherwise return
 ///
-/// # Examples
+/// With `WantExtract` as well, for each kind of newtype
 ///
-/// ```ignore
+/// ```
+/// use std::ops::Deref;
 /// use what_i_want::*;
 ///
-/// fn login(username: String) -> bool {
-///     require!(username == "admin", false);
+/// struct UserId(Option<u64>);
+/// impl_want_delegate!(UserId, |self| self.0 => Option<u64>);
+///
+/// struct Config {
+///     port: Result<u16, String>,
 /// }
+/// impl_want_delegate!(Config, |self| self.port => Result<u16, String>);
 ///
-/// fn login2(username: String) {
-///     require!(username == "admin");
+/// struct Checked(Option<char>);
+///
+/// impl Deref for Checked {
+///     type Target = Option<char>;
+///
+///     fn deref(&self) -> &Option<char> {
+///         &self.0
+///     }
+/// }
+/// impl_want_delegate!(Checked, |self| *self.deref() => Option<char>);
+///
+/// let mut ids = Vec::new();
+/// for id in vec![UserId(Some(1)), UserId(None), UserId(Some(3))] {
+///     ids.push(unwrap_or_continue!(id));
+/// }
+/// assert_eq!(ids, [1, 3]);
+///
+/// let mut ports = Vec::new();
+/// for config in vec![Config { port: Err("no port".into()) }, Config { port: Ok(80) }] {
+///     ports.push(unwrap_or_continue!(config));
+/// }
+/// assert_eq!(ports, [80]);
+///
+/// let mut chars = Vec::new();
+/// for checked in vec![Checked(None), Checked(Some('a'))] {
+///     chars.push(unwrap_or_continue!(checked));
 /// }
+/// assert_eq!(chars, ['a']);
+/// assert!(!Checked(None).is_i_want());
 /// ```
-macro_rules! require {
-    ($condition: expr) => {
-        if !$condition {
-            return;
+macro_rules! impl_want_delegate {
+    ($ty: ty, |$self: ident| $access: expr => $inner: ty) => {
+        impl_want_delegate!($ty, |$self| $access);
+
+        impl $crate::WantExtract for $ty {
+            type Output = <$inner as $crate::WantExtract>::Output;
+            type Rejected = <$inner as $crate::WantExtract>::Rejected;
+
+            fn ok_or_reject($self) -> Result<Self::Output, Self::Rejected> {
+                $crate::WantExtract::ok_or_reject($access)
+            }
         }
     };
-    ($condition: expr, $return: expr) => {
-        if !$condition {
-            return $return;
+    ($ty: ty, |$self: ident| $access: expr) => {
+        impl $crate::WhatIwant for $ty {
+            fn is_i_want(&$self) -> bool {
+                $crate::WhatIwant::is_i_want(&$access)
+            }
+
+            fn reject_reason(&$self) -> Option<String> {
+                $crate::WhatIwant::reject_reason(&$access)
+            }
+        }
+    };
+}
+
+mod pointers {
+    use crate::{WantExtract, WantExtractRef};
+    use std::mem::ManuallyDrop;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    impl_want_deref!(Box, Rc, Arc, ManuallyDrop);
+
+    /// What you want is moved out of the `Box`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use what_i_want::*;
+    ///
+    /// fn first_char(s: Box<Option<char>>) -> char {
+    ///     unwrap_or_val!(s, '?')
+    /// }
+    ///
+    /// assert_eq!(first_char(Box::new(Some('a'))), 'a');
+    /// assert_eq!(first_char(Box::new(None)), '?');
+    /// assert_eq!(Box::new(Ok::<i32, ()>(1)).wanted_ref(), Some(&1));
+    /// ```
+    impl<T: WantExtract> WantExtract for Box<T> {
+        type Output = T::Output;
+        type Rejected = T::Rejected;
+
+        fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected> {
+            (*self).ok_or_reject()
+        }
+    }
+
+    impl<T: WantExtractRef> WantExtractRef for Box<T> {
+        fn wanted_ref(&self) -> Option<&Self::Output> {
+            (**self).wanted_ref()
+        }
+    }
+
+    /// What you want is taken out of the `Rc` or `Arc`, or cloned out of it
+    /// while it's still shared
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use std::sync::Arc;
+    /// use what_i_want::*;
+    ///
+    /// fn port(config: Arc<Option<u16>>) -> u16 {
+    ///     unwrap_or_val!(config, 80)
+    /// }
+    ///
+    /// let shared = Arc::new(Some(8080));
+    /// assert_eq!(port(shared.clone()), 8080);
+    /// assert_eq!(port(shared), 8080);
+    /// assert_eq!(port(Arc::new(None)), 80);
+    /// assert_eq!(Rc::new(Err::<i32, ()>(())).ok_or_reject(), Err(()));
+    /// ```
+    impl<T: WantExtract + Clone> WantExtract for Rc<T> {
+        type Output = T::Output;
+        type Rejected = T::Rejected;
+
+        fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected> {
+            Rc::try_unwrap(self)
+                .unwrap_or_else(|shared| (*shared).clone())
+                .ok_or_reject()
+        }
+    }
+
+    impl<T: WantExtractRef + Clone> WantExtractRef for Rc<T> {
+        fn wanted_ref(&self) -> Option<&Self::Output> {
+            (**self).wanted_ref()
         }
+    }
+
+    impl<T: WantExtract + Clone> WantExtract for Arc<T> {
+        type Output = T::Output;
+        type Rejected = T::Rejected;
+
+        fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected> {
+            Arc::try_unwrap(self)
+                .unwrap_or_else(|shared| (*shared).clone())
+                .ok_or_reject()
+        }
+    }
+
+    impl<T: WantExtractRef + Clone> WantExtractRef for Arc<T> {
+        fn wanted_ref(&self) -> Option<&Self::Output> {
+            (**self).wanted_ref()
+        }
+    }
+
+    /// A `ManuallyDrop` is what you want when what it holds is, and what you
+    /// get out of it is still not dropped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::mem::ManuallyDrop;
+    /// use what_i_want::*;
+    ///
+    /// struct Counted<'a>(&'a Cell<i32>);
+    ///
+    /// impl Drop for Counted<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let drops = Cell::new(0);
+    /// let wrapped = ManuallyDrop::new(Some(Counted(&drops)));
+    /// assert!(wrapped.is_i_want());
+    /// assert!(!ManuallyDrop::new(None::<i32>).is_i_want());
+    ///
+    /// {
+    ///     let counted: ManuallyDrop<Counted> = unwrap_or_val!(wrapped, ());
+    ///     assert_eq!(counted.0.get(), 0);
+    /// }
+    /// assert_eq!(drops.get(), 0);
+    /// ```
+    impl<T: WantExtract> WantExtract for ManuallyDrop<T> {
+        type Output = ManuallyDrop<T::Output>;
+        type Rejected = ManuallyDrop<T::Rejected>;
+
+        fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected> {
+            match ManuallyDrop::into_inner(self).ok_or_reject() {
+                Ok(want) => Ok(ManuallyDrop::new(want)),
+                Err(rejected) => Err(ManuallyDrop::new(rejected)),
+            }
+        }
+    }
+}
+
+/// A `NonZero` number is always what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::num::*;
+/// use what_i_want::WhatIwant;
+///
+/// assert!(NonZeroU8::new(1).unwrap().is_i_want());
+/// assert!(NonZeroU16::new(1).unwrap().is_i_want());
+/// assert!(NonZeroU32::new(1).unwrap().is_i_want());
+/// assert!(NonZeroU64::new(1).unwrap().is_i_want());
+/// assert!(NonZeroU128::new(1).unwrap().is_i_want());
+/// assert!(NonZeroUsize::new(1).unwrap().is_i_want());
+/// assert!(NonZeroI8::new(-1).unwrap().is_i_want());
+/// assert!(NonZeroI16::new(-1).unwrap().is_i_want());
+/// assert!(NonZeroI32::new(-1).unwrap().is_i_want());
+/// assert!(NonZeroI64::new(-1).unwrap().is_i_want());
+/// assert!(NonZeroI128::new(-1).unwrap().is_i_want());
+/// assert!(NonZeroIsize::new(-1).unwrap().is_i_want());
+/// ```
+macro_rules! impl_want_nonzero {
+    ($($ty: ty),*) => {
+        $(
+            impl WhatIwant for $ty {
+                fn is_i_want(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_want_nonzero!(
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroUsize,
+    std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128,
+    std::num::NonZeroIsize
+);
+
+/// A non-empty `BTreeSet` is what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use what_i_want::*;
+///
+/// let sets: Vec<BTreeSet<i32>> = vec![
+///     [1, 2].iter().copied().collect(),
+///     BTreeSet::new(),
+///     [3].iter().copied().collect(),
+/// ];
+/// let mut sizes = Vec::new();
+/// for set in sets {
+///     let set = unwrap_or_continue!(set);
+///     sizes.push(set.len());
+/// }
+/// assert_eq!(sizes, vec![2, 1]);
+///
+/// // Skip empty intersections
+/// let evens: BTreeSet<i32> = [2, 4, 6].iter().copied().collect();
+/// let groups: Vec<BTreeSet<i32>> = vec![
+///     [1, 2, 3].iter().copied().collect(),
+///     [5, 7].iter().copied().collect(),
+///     [4, 6, 8].iter().copied().collect(),
+/// ];
+/// let mut common = Vec::new();
+/// for group in &groups {
+///     let intersection = unwrap_or_continue!(group
+///         .intersection(&evens)
+///         .copied()
+///         .collect::<BTreeSet<i32>>());
+///     common.push(intersection);
+/// }
+/// assert_eq!(common.len(), 2);
+/// ```
+impl<T> WhatIwant for std::collections::BTreeSet<T> {
+    fn is_i_want(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> WantExtract for std::collections::BTreeSet<T> {
+    type Output = Self;
+    type Rejected = ();
+
+    fn ok_or_reject(self) -> Result<Self, ()> {
+        if self.is_empty() {
+            Err(())
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl<T> WantExtractRef for std::collections::BTreeSet<T> {
+    fn wanted_ref(&self) -> Option<&Self> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+/// An `Occupied` entry of a `HashMap` is what you want, the key is there
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use what_i_want::*;
+///
+/// let mut stock: HashMap<&str, u32> = HashMap::new();
+/// stock.insert("apple", 3);
+/// stock.insert("pear", 1);
+///
+/// let mut restocked = Vec::new();
+/// for &fruit in &["apple", "kiwi", "pear", "plum"] {
+///     let mut entry = unwrap_or_continue!(stock.entry(fruit));
+///     *entry.get_mut() += 10;
+///     restocked.push(fruit);
+/// }
+/// assert_eq!(restocked, vec!["apple", "pear"]);
+/// assert_eq!(stock["apple"], 13);
+/// assert_eq!(stock["pear"], 11);
+/// assert!(!stock.contains_key("kiwi"));
+///
+/// let vacant = unwrap_or_do!(not_want(stock.entry("kiwi")), unreachable!());
+/// vacant.insert(1);
+/// assert_eq!(stock["kiwi"], 1);
+/// ```
+impl<'a, K, V> WhatIwant for std::collections::hash_map::Entry<'a, K, V> {
+    fn is_i_want(&self) -> bool {
+        matches!(self, std::collections::hash_map::Entry::Occupied(_))
+    }
+}
+
+impl<'a, K, V> WantExtract for std::collections::hash_map::Entry<'a, K, V> {
+    type Output = std::collections::hash_map::OccupiedEntry<'a, K, V>;
+    type Rejected = std::collections::hash_map::VacantEntry<'a, K, V>;
+
+    fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected> {
+        match self {
+            std::collections::hash_map::Entry::Occupied(entry) => Ok(entry),
+            std::collections::hash_map::Entry::Vacant(entry) => Err(entry),
+        }
+    }
+}
+
+/// A `Ready` poll is what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::task::Poll;
+/// use what_i_want::*;
+///
+/// assert!(Poll::Ready(1).is_i_want());
+/// assert!(!Poll::<i32>::Pending.is_i_want());
+/// ```
+impl<T> WhatIwant for std::task::Poll<T> {
+    fn is_i_want(&self) -> bool {
+        self.is_ready()
+    }
+}
+
+impl<T> WantExtract for std::task::Poll<T> {
+    type Output = T;
+    type Rejected = ();
+
+    fn ok_or_reject(self) -> Result<T, ()> {
+        match self {
+            std::task::Poll::Ready(value) => Ok(value),
+            std::task::Poll::Pending => Err(()),
+        }
+    }
+}
+
+impl<T> WantExtractRef for std::task::Poll<T> {
+    fn wanted_ref(&self) -> Option<&T> {
+        match self {
+            std::task::Poll::Ready(value) => Some(value),
+            std::task::Poll::Pending => None,
+        }
+    }
+}
+
+/// A `Mutex` that is not poisoned is what you want
+///
+/// An `Arc<Mutex<T>>` delegates to it, so shared state can be guarded with
+/// [`require!`]; getting what you want out of a `&Mutex<T>` or an
+/// `&Arc<Mutex<T>>` locks it
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+/// use what_i_want::*;
+///
+/// fn add(shared: &Arc<Mutex<Vec<i32>>>, value: i32) -> bool {
+///     require!(shared.is_i_want(), false);
+///     let mut values = unwrap_or_false!(shared);
+///     values.push(value);
+///     true
+/// }
+///
+/// let shared = Arc::new(Mutex::new(Vec::new()));
+/// assert!(add(&shared, 1));
+///
+/// let poisoner = Arc::clone(&shared);
+/// let _ = thread::spawn(move || {
+///     let _guard = poisoner.lock().unwrap();
+///     panic!("poison the lock");
+/// })
+/// .join();
+///
+/// assert!(!shared.is_i_want());
+/// assert_eq!(shared.reject_reason().as_deref(), Some("the lock is poisoned"));
+/// assert!(!add(&shared, 2));
+/// assert!((&*shared).ok_or_reject().is_err());
+/// assert!((&shared).ok_or_reject().is_err());
+/// assert_eq!(*shared.lock().unwrap_or_else(|e| e.into_inner()), vec![1]);
+/// ```
+impl<T: ?Sized> WhatIwant for std::sync::Mutex<T> {
+    fn is_i_want(&self) -> bool {
+        !self.is_poisoned()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        if self.is_poisoned() {
+            Some("the lock is poisoned".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: ?Sized> WhatIwant for &std::sync::Mutex<T> {
+    fn is_i_want(&self) -> bool {
+        (**self).is_i_want()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        (**self).reject_reason()
+    }
+}
+
+impl<'a, T: ?Sized> WantExtract for &'a std::sync::Mutex<T> {
+    type Output = std::sync::MutexGuard<'a, T>;
+    type Rejected = std::sync::PoisonError<std::sync::MutexGuard<'a, T>>;
+
+    fn ok_or_reject(self) -> std::sync::LockResult<std::sync::MutexGuard<'a, T>> {
+        self.lock()
+    }
+}
+
+impl<T: ?Sized> WhatIwant for &std::sync::Arc<std::sync::Mutex<T>> {
+    fn is_i_want(&self) -> bool {
+        (***self).is_i_want()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        (***self).reject_reason()
+    }
+}
+
+impl<'a, T: ?Sized> WantExtract for &'a std::sync::Arc<std::sync::Mutex<T>> {
+    type Output = std::sync::MutexGuard<'a, T>;
+    type Rejected = std::sync::PoisonError<std::sync::MutexGuard<'a, T>>;
+
+    fn ok_or_reject(self) -> std::sync::LockResult<std::sync::MutexGuard<'a, T>> {
+        self.lock()
+    }
+}
+
+/// The standard streams are what you want, as locking them always succeeds
+///
+/// Getting what you want out of them blocks until their lock is acquired,
+/// see [`TryLockWant`] for locks that may not be acquired
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Write};
+/// use what_i_want::*;
+///
+/// assert!(io::stdin().is_i_want());
+/// assert!(io::stdout().is_i_want());
+/// assert!(io::stderr().is_i_want());
+///
+/// let mut out = io::stdout().ok_or_reject().unwrap();
+/// writeln!(out, "locked").unwrap();
+/// ```
+macro_rules! impl_want_stdio {
+    ($($stdio: ty => $lock: ty),*) => {
+        $(
+            impl WhatIwant for $stdio {
+                fn is_i_want(&self) -> bool {
+                    true
+                }
+            }
+
+            impl WantExtract for $stdio {
+                type Output = $lock;
+                type Rejected = std::convert::Infallible;
+
+                fn ok_or_reject(self) -> Result<$lock, std::convert::Infallible> {
+                    Ok(self.lock())
+                }
+            }
+        )*
+    };
+}
+
+impl_want_stdio!(
+    std::io::Stdin => std::io::StdinLock<'static>,
+    std::io::Stdout => std::io::StdoutLock<'static>,
+    std::io::Stderr => std::io::StderrLock<'static>
+);
+
+/// A `Normal` float is what you want
+///
+/// See [`WantFpCategory`] to want another category
+///
+/// # Examples
+///
+/// ```
+/// use std::num::FpCategory;
+/// use what_i_want::*;
+///
+/// assert!(FpCategory::Normal.is_i_want());
+/// assert!(!FpCategory::Nan.is_i_want());
+/// assert!(!FpCategory::Infinite.is_i_want());
+/// assert!(!FpCategory::Zero.is_i_want());
+/// assert!(!FpCategory::Subnormal.is_i_want());
+/// ```
+impl WhatIwant for std::num::FpCategory {
+    fn is_i_want(&self) -> bool {
+        matches!(self, std::num::FpCategory::Normal)
+    }
+}
+
+/// A parse error can only be a failure, so it is never what you want
+///
+/// See [`WantParsed`] and [`try_parse!`] to parse what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert!(!"x".parse::<i32>().unwrap_err().is_i_want());
+/// assert!(!"x".parse::<f64>().unwrap_err().is_i_want());
+/// ```
+impl WhatIwant for std::num::ParseIntError {
+    fn is_i_want(&self) -> bool {
+        false
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl WhatIwant for std::num::ParseFloatError {
+    fn is_i_want(&self) -> bool {
+        false
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// A `Reverse` is what you want when what it wraps is not, the same as
+/// [`not_want`](fn@not_want)
+///
+/// It's for the "skip if it exists" pattern: getting what you want out of it
+/// gives what the wrapped value rejected, and the other way around
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use std::collections::HashMap;
+/// use what_i_want::*;
+///
+/// assert!(!Reverse(Some(1)).is_i_want());
+/// assert!(Reverse(None::<i32>).is_i_want());
+/// assert_eq!(Reverse(Err::<i32, _>("bad")).ok_or_reject(), Ok("bad"));
+/// assert_eq!(Reverse(Ok::<_, &str>(1)).ok_or_reject(), Err(1));
+///
+/// // Only insert the keys that are not there yet
+/// let mut cache: HashMap<&str, usize> = HashMap::new();
+/// cache.insert("b", 0);
+/// let mut inserted = Vec::new();
+/// for key in vec!["a", "b", "c"] {
+///     unwrap_or_continue!(Reverse(cache.get(key)));
+///     cache.insert(key, key.len());
+///     inserted.push(key);
+/// }
+/// assert_eq!(inserted, vec!["a", "c"]);
+/// ```
+impl<T: WhatIwant> WhatIwant for std::cmp::Reverse<T> {
+    fn is_i_want(&self) -> bool {
+        !self.0.is_i_want()
+    }
+}
+
+impl<T: WantExtract> WantExtract for std::cmp::Reverse<T> {
+    type Output = T::Rejected;
+    type Rejected = T::Output;
+
+    fn ok_or_reject(self) -> Result<T::Rejected, T::Output> {
+        match self.0.ok_or_reject() {
+            Ok(output) => Err(output),
+            Err(rejected) => Ok(rejected),
+        }
+    }
+}
+
+/// `Infallible` can never exist, so whatever `is_i_want` says about it is
+/// vacuously true, the body is unreachable
+///
+/// This impl doesn't overlap the `Result` one: a `Result<T, Infallible>` is
+/// always `Ok`, so it's always what you want through the `Result` impl, and
+/// nothing can be rejected out of it
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::Infallible;
+/// use what_i_want::*;
+///
+/// fn assert_impl<T: WhatIwant>() {}
+/// assert_impl::<Infallible>();
+/// assert_impl::<Result<i32, Infallible>>();
+/// assert_impl::<Result<Infallible, i32>>();
+/// assert_impl::<Option<Infallible>>();
+///
+/// assert!(Result::<i32, Infallible>::Ok(5).is_i_want());
+/// let value = match Result::<i32, Infallible>::Ok(5).ok_or_reject() {
+///     Ok(value) => value,
+///     Err(never) => match never {},
+/// };
+/// assert_eq!(value, 5);
+///
+/// // Never what you want, as it can never be `Ok`
+/// assert!(!Result::<Infallible, i32>::Err(1).is_i_want());
+/// ```
+impl WhatIwant for std::convert::Infallible {
+    fn is_i_want(&self) -> bool {
+        match *self {}
+    }
+}
+
+#[macro_export]
+/// If it's not what you want, then do what you want
+///
+/// What you want is got out of it with [`WantExtract`], so every macro built on
+/// this one needs the expression's type to implement `WantExtract` as well as
+/// `WhatIwant`. Types that only implement `WhatIwant` still work with
+/// `is_i_want` and [`require!`], and [`impl_want_delegate!`] can implement
+/// both for a wrapper.
+///
+/// This used to call `.unwrap()` on the expression, which evaluated it twice,
+/// ran `$do` when it *was* what you want, and only worked for types with an
+/// inherent `unwrap` method.
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_do!($exp, $do)
+/// // expands to
+/// match $crate::WantExtract::ok_or_reject($exp) {
+///     Ok(want) => want,
+///     Err(_) => $do,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let an_err: Result<Option<i32>, ()> = Ok(Some(1));
+/// let an_option: Option<Option<i32>> = Some(Some(1));
+///
+/// unwrap_or_do!(an_err, Some(0));
+/// unwrap_or_do!(an_option, Some(0));
+///
+/// fn a_func() -> bool {
+///     let an_option = Some(true);
+///     unwrap_or_do!(an_option, return false)
+/// }
+///
+/// ```
+macro_rules! unwrap_or_do {
+    ($exp: expr, $do: expr) => {
+        match $crate::WantExtract::ok_or_reject($exp) {
+            Ok(want) => want,
+            Err(_) => $do,
+        }
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `continue`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_continue!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, continue)
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use what_i_want::*;
+///
+/// async fn get_mutipart_data(mut mutipart_data: Multipart) -> MultipartData {
+///     while let Some(Ok(mut field)) = mutipart_data.next().await {
+///         let disposition = unwrap_or_continue!(field.headers().get(&header::CONTENT_DISPOSITION));
+///         let disposition_str = unwrap_or_continue!(disposition.to_str());
+///         let dis = unwrap_or_continue!(ContentDisposition::parse(disposition_str));
+///         let key = unwrap_or_continue!(dis.name);
+///         while let Some(Ok(chunk)) = field.next().await {
+///             ...
+///         }
+///     }
+///     MultipartData { ... }
+/// }
+/// ```
+macro_rules! unwrap_or_continue {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, continue)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `break`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_break!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, break)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use what_i_want::*;
+///
+/// let sum = block_on(async {
+///     let mut items = stream::iter(vec![1, 2, 3]);
+///     let mut sum = 0;
+///     loop {
+///         let item = unwrap_or_break!(AsyncStreamItem(items.next().await));
+///         sum += item;
+///     }
+///     sum
+/// });
+/// assert_eq!(sum, 6);
+/// ```
+macro_rules! unwrap_or_break {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, break)
+    };
+}
+
+#[macro_export]
+/// Extract what you want; if it's not what you want, then do `continue`
+///
+/// Same as [`unwrap_or_continue!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// extract_or_continue!($exp)
+/// // expands to
+/// unwrap_or_continue!($exp)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let items = vec!["1", "oops", "3"];
+///
+/// let mut extracted = Vec::new();
+/// for item in &items {
+///     extracted.push(extract_or_continue!(item.parse::<i32>()));
+/// }
+///
+/// let mut unwrapped = Vec::new();
+/// for item in &items {
+///     unwrapped.push(unwrap_or_continue!(item.parse::<i32>()));
+/// }
+///
+/// assert_eq!(extracted, unwrapped);
+/// ```
+macro_rules! extract_or_continue {
+    ($exp: expr) => {
+        unwrap_or_continue!($exp)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return ()`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_return!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, return)
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use what_i_want::*;
+///
+/// fn a_func(result: Result<i32, ()>) -> () {
+///     let unwrapped = unwrap_or_return!(result);
+///     // If ok, do something, else return
+///     ...
+/// }
+/// ```
+macro_rules! unwrap_or_return {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, return)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return false`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_false!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, return false)
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use what_i_want::*;
+///
+/// fn a_func(result: Result<i32, ()>) -> bool {
+///     let unwrapped = unwrap_or_false!(result);
+///     // If ok, do something, else return
+///     ...
+/// }
+/// ```
+macro_rules! unwrap_or_false {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, return false)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return true`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_true!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, return true)
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use what_i_want::*;
+///
+/// fn a_func(result: Result<i32, ()>) -> bool {
+///     let unwrapped = unwrap_or_true!(result);
+///     // If ok, do something, else return
+///     ...
+/// }
+/// ```
+macro_rules! unwrap_or_true {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, return true)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return <defined return value>`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_val!($exp, $val)
+/// // expands to
+/// unwrap_or_do!($exp, return $val)
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use what_i_want::*;
+///
+/// fn a_func(result: Result<i32, ()>) -> bool {
+///     let unwrapped = unwrap_or_val!(result, false);
+///     // If ok, do something, else return
+///     ...
+/// }
+/// ```
+macro_rules! unwrap_or_val {
+    ($exp: expr, $val: expr) => {
+        unwrap_or_do!($exp, return $val)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return <computed return value>`
+///
+/// The closure is only called when it's not what you want
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_val_with!($exp, $f)
+/// // expands to
+/// unwrap_or_do!($exp, return ($f)())
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use what_i_want::*;
+///
+/// fn a_func(result: Result<i32, ()>, calls: &Cell<i32>) -> i32 {
+///     let unwrapped = unwrap_or_val_with!(result, || {
+///         calls.set(calls.get() + 1);
+///         -1
+///     });
+///     unwrapped * 2
+/// }
+///
+/// let calls = Cell::new(0);
+/// assert_eq!(a_func(Ok(2), &calls), 4);
+/// assert_eq!(calls.get(), 0);
+/// assert_eq!(a_func(Err(()), &calls), -1);
+/// assert_eq!(calls.get(), 1);
+/// ```
+macro_rules! unwrap_or_val_with {
+    ($exp: expr, $f: expr) => {
+        unwrap_or_do!($exp, return ($f)())
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return None`
+///
+/// Like `?` in a function returning `Option`, but works with anything you
+/// can get what you want out of, discarding what was rejected
+///
+/// # Expansion
+///
+/// ```ignore
+/// some_try!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, return None)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn double_first(items: &[&str]) -> Option<i32> {
+///     let first = some_try!(items.first());
+///     let number = some_try!(first.parse::<i32>());
+///     Some(number * 2)
+/// }
+///
+/// assert_eq!(double_first(&["2"]), Some(4));
+/// assert_eq!(double_first(&["oops"]), None);
+/// assert_eq!(double_first(&[]), None);
+/// ```
+macro_rules! some_try {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, return None)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return None`, same as [`some_try!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_none!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, return None)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn first_char(s: &str) -> Option<char> {
+///     Some(unwrap_or_none!(s.chars().next()))
+/// }
+///
+/// fn first_char_try(s: &str) -> Option<char> {
+///     Some(some_try!(s.chars().next()))
+/// }
+///
+/// for s in &["abc", ""] {
+///     assert_eq!(first_char(s), first_char_try(s));
+/// }
+/// ```
+macro_rules! unwrap_or_none {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, return None)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `return None`, same as [`unwrap_or_none!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_return_none!($exp)
+/// // expands to
+/// unwrap_or_none!($exp)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn first_char(s: &str) -> Option<char> {
+///     Some(unwrap_or_return_none!(s.chars().next()))
+/// }
+///
+/// fn first_char_none(s: &str) -> Option<char> {
+///     Some(unwrap_or_none!(s.chars().next()))
+/// }
+///
+/// for s in &["abc", ""] {
+///     assert_eq!(first_char(s), first_char_none(s));
+/// }
+/// ```
+macro_rules! unwrap_or_return_none {
+    ($exp: expr) => {
+        unwrap_or_none!($exp)
+    };
+}
+
+#[macro_export]
+/// Negate what you want, see [`Negated`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// not_want!($exp)
+/// // expands to
+/// $crate::not_want($exp)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(not_want!(Some(1)).is_i_want(), false);
+/// assert_eq!(not_want!(None::<i32>).is_i_want(), true);
+///
+/// let skips = vec![None, Some("skip"), None];
+/// let mut processed = 0;
+/// for skip in skips {
+///     unwrap_or_continue!(not_want!(skip));
+///     processed += 1;
+/// }
+/// assert_eq!(processed, 2);
+/// ```
+macro_rules! not_want {
+    ($exp: expr) => {
+        $crate::not_want($exp)
+    };
+}
+
+#[macro_export]
+/// Want both of them, see [`WantAnd`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_and!($a, $b)
+/// // expands to
+/// $crate::want_and($a, $b)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut pairs = Vec::new();
+/// for (a, b) in vec![(Some(1), Some('a')), (None, Some('b')), (Some(3), None)] {
+///     pairs.push(unwrap_or_continue!(want_and!(a, b)));
+/// }
+/// assert_eq!(pairs, vec![(1, 'a')]);
+/// ```
+macro_rules! want_and {
+    ($a: expr, $b: expr) => {
+        $crate::want_and($a, $b)
+    };
+}
+
+#[macro_export]
+/// Want either of them, the first one first, see [`WantOr`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_or!($a, $b)
+/// // expands to
+/// $crate::want_or($a, $b)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut ports = Vec::new();
+/// for (flag, env) in vec![(Some(80), Some(8080)), (None, Some(8080)), (None, None)] {
+///     ports.push(unwrap_or_continue!(want_or!(flag, env)));
+/// }
+/// assert_eq!(ports, vec![80, 8080]);
+/// ```
+macro_rules! want_or {
+    ($a: expr, $b: expr) => {
+        $crate::want_or($a, $b)
+    };
+}
+
+#[macro_export]
+/// Execute if the condition is true, otherwise return
+///
+/// # Expansion
+///
+/// ```ignore
+/// require!($condition)
+/// // expands to
+/// if !$condition {
+///     return;
+/// }
+///
+/// require!($condition, $return)
+/// // expands to
+/// if !$condition {
+///     return $return;
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use what_i_want::*;
+///
+/// fn login(username: String) -> bool {
+///     require!(username == "admin", false);
+/// }
+///
+/// fn login2(username: String) {
+///     require!(username == "admin");
+/// }
+/// ```
+macro_rules! require {
+    ($condition: expr) => {
+        if !$condition {
+            return;
+        }
+    };
+    ($condition: expr, $return: expr) => {
+        if !$condition {
+            return $return;
+        }
+    };
+}
+
+#[macro_export]
+/// Execute if the current state is the expected state, otherwise return
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_state!($current, $expected)
+/// // expands to
+/// require!($crate::WhatIwant::is_i_want(&$crate::CurrentState::new(
+///     &$current, &$expected
+/// )))
+///
+/// require_state!($current, $expected, $return)
+/// // expands to
+/// require!(
+///     $crate::WhatIwant::is_i_want(&$crate::CurrentState::new(&$current, &$expected)),
+///     $return
+/// )
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Door {
+///     Open,
+///     Closed,
+///     Locked,
+/// }
+///
+/// struct Doorway {
+///     state: Door,
+/// }
+///
+/// impl Doorway {
+///     fn open(&mut self) -> bool {
+///         require_state!(self.state, Door::Closed, false);
+///         self.state = Door::Open;
+///         true
+///     }
+///
+///     fn close(&mut self) {
+///         require_state!(self.state, Door::Open);
+///         self.state = Door::Closed;
+///     }
+///
+///     fn lock(&mut self) -> bool {
+///         require_state!(self.state, Door::Closed, false);
+///         self.state = Door::Locked;
+///         true
+///     }
+/// }
+///
+/// let mut door = Doorway { state: Door::Closed };
+/// assert!(door.lock());
+/// assert!(!door.open());
+/// assert_eq!(door.state, Door::Locked);
+///
+/// door.state = Door::Closed;
+/// assert!(door.open());
+/// assert!(!door.lock());
+/// door.close();
+/// assert_eq!(door.state, Door::Closed);
+/// ```
+macro_rules! require_state {
+    ($current: expr, $expected: expr) => {
+        require!($crate::WhatIwant::is_i_want(&$crate::CurrentState::new(
+            &$current, &$expected
+        )))
+    };
+    ($current: expr, $expected: expr, $return: expr) => {
+        require!(
+            $crate::WhatIwant::is_i_want(&$crate::CurrentState::new(&$current, &$expected)),
+            $return
+        )
+    };
+}
+
+#[macro_export]
+/// If the `OnceLock` (or `OnceCell`) is not initialized, then return
+///
+/// # Expansion
+///
+/// ```ignore
+/// once_or_return!($lock)
+/// // expands to
+/// unwrap_or_do!($lock.get(), return)
+///
+/// once_or_return!($lock, $return)
+/// // expands to
+/// unwrap_or_do!($lock.get(), return $return)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use what_i_want::*;
+///
+/// fn a_func(lock: &OnceLock<i32>) -> i32 {
+///     let value: &i32 = once_or_return!(lock, -1);
+///     *value * 2
+/// }
+///
+/// let lock = OnceLock::new();
+/// assert_eq!(a_func(&lock), -1);
+/// lock.set(2).unwrap();
+/// assert_eq!(a_func(&lock), 4);
+/// ```
+macro_rules! once_or_return {
+    ($lock: expr) => {
+        unwrap_or_do!($lock.get(), return)
+    };
+    ($lock: expr, $return: expr) => {
+        unwrap_or_do!($lock.get(), return $return)
+    };
+}
+
+#[macro_export]
+/// If the `OnceLock` (or `OnceCell`) is not initialized, initialize it with the
+/// closure; if the closure does not give what you want, then return
+///
+/// The closure is only called when the lock is not initialized
+///
+/// # Expansion
+///
+/// ```ignore
+/// once_init_or_return!($lock, $init)
+/// // expands to
+/// match &$lock {
+///     lock => match lock.get() {
+///         Some(value) => value,
+///         None => {
+///             let value = unwrap_or_do!(($init)(), return);
+///             lock.get_or_init(|| value)
+///         }
+///     },
+/// }
+///
+/// once_init_or_return!($lock, $init, $return)
+/// // expands to
+/// match &$lock {
+///     lock => match lock.get() {
+///         Some(value) => value,
+///         None => {
+///             let value = unwrap_or_do!(($init)(), return $return);
+///             lock.get_or_init(|| value)
+///         }
+///     },
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use what_i_want::*;
+///
+/// fn a_func(lock: &OnceLock<i32>, input: &str) -> i32 {
+///     let value: &i32 = once_init_or_return!(lock, || input.parse::<i32>(), -1);
+///     *value * 2
+/// }
+///
+/// let lock = OnceLock::new();
+/// assert_eq!(a_func(&lock, "oops"), -1);
+/// assert!(lock.get().is_none());
+/// assert_eq!(a_func(&lock, "2"), 4);
+/// assert_eq!(a_func(&lock, "oops"), 4);
+/// ```
+macro_rules! once_init_or_return {
+    ($lock: expr, $init: expr) => {
+        match &$lock {
+            lock => match lock.get() {
+                Some(value) => value,
+                None => {
+                    let value = unwrap_or_do!(($init)(), return);
+                    lock.get_or_init(|| value)
+                }
+            },
+        }
+    };
+    ($lock: expr, $init: expr, $return: expr) => {
+        match &$lock {
+            lock => match lock.get() {
+                Some(value) => value,
+                None => {
+                    let value = unwrap_or_do!(($init)(), return $return);
+                    lock.get_or_init(|| value)
+                }
+            },
+        }
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then attach context to what was rejected and
+/// do `return Err(<context>)`
+///
+/// What was rejected is the `Err` of a `Result`, `()` of an `Option`, or the
+/// `WantExtract::Rejected` of your own type. Wrap it in a [`WhatIwantError`]
+/// to keep the context of every layer it passes through
+///
+/// # Expansion
+///
+/// ```ignore
+/// context_unwrap_or_return!($exp, $context)
+/// // expands to
+/// match $crate::WantExtract::ok_or_reject($exp) {
+///     Ok(value) => value,
+///     Err(rejected) => return Err(($context)(rejected)),
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// #[derive(Debug)]
+/// struct MyError(String);
+///
+/// impl MyError {
+///     fn wrap(self, context: &str) -> MyError {
+///         MyError(format!("{}: {}", context, self.0))
+///     }
+/// }
+///
+/// fn parse(input: &str) -> Result<i32, MyError> {
+///     let number = context_unwrap_or_return!(input.parse::<i32>(), |e| MyError(format!("{}", e)));
+///     Ok(number)
+/// }
+///
+/// fn load(input: &str) -> Result<i32, MyError> {
+///     let number = context_unwrap_or_return!(parse(input), |e: MyError| e.wrap("while loading"));
+///     Ok(number)
+/// }
+///
+/// fn handle(input: &str) -> Result<i32, MyError> {
+///     let number = context_unwrap_or_return!(load(input), |e: MyError| e.wrap("while processing request"));
+///     Ok(number * 2)
+/// }
+///
+/// assert_eq!(handle("2").unwrap(), 4);
+/// assert_eq!(
+///     handle("oops").unwrap_err().0,
+///     "while processing request: while loading: invalid digit found in string"
+/// );
+/// ```
+///
+/// With [`WhatIwantError`] carrying the context
+///
+/// ```
+/// use std::num::ParseIntError;
+/// use what_i_want::*;
+///
+/// fn parse(input: &str) -> Result<i32, WhatIwantError<ParseIntError>> {
+///     let number = context_unwrap_or_return!(input.parse::<i32>(), |e| {
+///         WhatIwantError::new(e).context(format!("while parsing {:?}", input))
+///     });
+///     Ok(number)
+/// }
+///
+/// fn load(input: &str) -> Result<i32, WhatIwantError<ParseIntError>> {
+///     let number = context_unwrap_or_return!(parse(input), |e: WhatIwantError<_>| {
+///         e.context("while loading")
+///     });
+///     Ok(number)
+/// }
+///
+/// fn handle(input: &str) -> Result<i32, WhatIwantError<ParseIntError>> {
+///     let number = context_unwrap_or_return!(load(input), |e: WhatIwantError<_>| {
+///         e.context("while processing request")
+///     });
+///     Ok(number * 2)
+/// }
+///
+/// assert_eq!(handle("2").unwrap(), 4);
+/// let error = handle("oops").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "while processing request: while loading: while parsing \"oops\": invalid digit found in string"
+/// );
+/// assert_eq!(error.contexts().count(), 3);
+/// ```
+macro_rules! context_unwrap_or_return {
+    ($exp: expr, $context: expr) => {
+        match $crate::WantExtract::ok_or_reject($exp) {
+            Ok(value) => value,
+            Err(rejected) => return Err(($context)(rejected)),
+        }
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `continue`, calling the closure at most
+/// `max_per_second` times per second
+///
+/// Each call site keeps its own count, so a failure action in a hot loop
+/// (e.g. a log or a metric) doesn't spam
+///
+/// # Expansion
+///
+/// ```ignore
+/// throttle_want!($exp, max_per_second: $max, $f)
+/// // expands to
+/// unwrap_or_do!($exp, {
+///     static THROTTLE: $crate::Throttle = $crate::Throttle::new();
+///     if THROTTLE.allow($max) {
+///         ($f)();
+///     }
+///     continue;
+/// })
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut fired = 0;
+/// let mut processed = 0;
+/// for i in 0..110 {
+///     let item: Option<i32> = if i < 100 { None } else { Some(i) };
+///     let value = throttle_want!(item, max_per_second: 10, || fired += 1);
+///     processed += value;
+/// }
+/// assert!(fired >= 1 && fired <= 10);
+/// assert_eq!(processed, (100..110).sum::<i32>());
+/// ```
+macro_rules! throttle_want {
+    ($exp: expr, max_per_second: $max: expr, $f: expr) => {
+        unwrap_or_do!($exp, {
+            static THROTTLE: $crate::Throttle = $crate::Throttle::new();
+            if THROTTLE.allow($max) {
+                ($f)();
+            }
+            continue;
+        })
+    };
+}
+
+#[macro_export]
+/// Check that your `WhatIwant` and `WantExtract` impls agree with each other
+///
+/// Panics unless the first value is what you want and can be extracted, and
+/// the second value is not what you want and is rejected
+///
+/// # Expansion
+///
+/// ```ignore
+/// test_conformance!($wanted, $rejected)
+/// // expands to
+/// {
+///     let wanted = $wanted;
+///     assert!(
+///         $crate::WhatIwant::is_i_want(&wanted),
+///         "`{}` should be what I want",
+///         stringify!($wanted)
+///     );
+///     assert!(
+///         $crate::WantExtract::ok_or_reject(wanted).is_ok(),
+///         "`{}` is what I want but could not be extracted",
+///         stringify!($wanted)
+///     );
+///     let rejected = $rejected;
+///     assert!(
+///         !$crate::WhatIwant::is_i_want(&rejected),
+///         "`{}` should not be what I want",
+///         stringify!($rejected)
+///     );
+///     assert!(
+///         $crate::WantExtract::ok_or_reject(rejected).is_err(),
+///         "`{}` is not what I want but was extracted",
+///         stringify!($rejected)
+///     );
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// enum LoginReply {
+///     Success(String),
+///     Failed(i32),
+/// }
+///
+/// impl WhatIwant for LoginReply {
+///     fn is_i_want(&self) -> bool {
+///         matches!(self, LoginReply::Success(_))
+///     }
+/// }
+///
+/// impl WantExtract for LoginReply {
+///     type Output = String;
+///     type Rejected = i32;
+///
+///     fn ok_or_reject(self) -> Result<String, i32> {
+///         match self {
+///             LoginReply::Success(token) => Ok(token),
+///             LoginReply::Failed(code) => Err(code),
+///         }
+///     }
+/// }
+///
+/// what_i_want::test_conformance!(LoginReply::Success("token".into()), LoginReply::Failed(403));
+/// what_i_want::test_conformance!(Some(1), None::<i32>);
+/// what_i_want::test_conformance!(Ok::<i32, ()>(1), Err::<i32, ()>(()));
+/// ```
+///
+/// ```should_panic
+/// what_i_want::test_conformance!(None::<i32>, Some(1));
+/// ```
+macro_rules! test_conformance {
+    ($wanted: expr, $rejected: expr) => {{
+        let wanted = $wanted;
+        assert!(
+            $crate::WhatIwant::is_i_want(&wanted),
+            "`{}` should be what I want",
+            stringify!($wanted)
+        );
+        assert!(
+            $crate::WantExtract::ok_or_reject(wanted).is_ok(),
+            "`{}` is what I want but could not be extracted",
+            stringify!($wanted)
+        );
+        let rejected = $rejected;
+        assert!(
+            !$crate::WhatIwant::is_i_want(&rejected),
+            "`{}` should not be what I want",
+            stringify!($rejected)
+        );
+        assert!(
+            $crate::WantExtract::ok_or_reject(rejected).is_err(),
+            "`{}` is not what I want but was extracted",
+            stringify!($rejected)
+        );
+    }};
+}
+
+#[cfg(feature = "chrono")]
+#[macro_export]
+/// Parse a `DateTime` with the format; if it can't be parsed, then do `continue`
+///
+/// # Expansion
+///
+/// ```ignore
+/// parse_date_or_continue!($s, $fmt)
+/// // expands to
+/// unwrap_or_continue!($crate::chrono::DateTime::parse_from_str($s, $fmt))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let dates = vec![
+///     "2020-01-01 00:00:00 +0000",
+///     "not a date",
+///     "2020-02-30 00:00:00 +0000",
+///     "2021-06-15 12:30:00 +0800",
+/// ];
+/// let mut years = Vec::new();
+/// for date in dates {
+///     let date = parse_date_or_continue!(date, "%Y-%m-%d %H:%M:%S %z");
+///     years.push(chrono::Datelike::year(&date));
+/// }
+/// assert_eq!(years, vec![2020, 2021]);
+/// ```
+macro_rules! parse_date_or_continue {
+    ($s: expr, $fmt: expr) => {
+        unwrap_or_continue!($crate::chrono::DateTime::parse_from_str($s, $fmt))
+    };
+}
+
+#[macro_export]
+/// Peel off `depth` layers of what you want; if any layer is not what you
+/// want, then do what you want
+///
+/// `depth` is a literal from `1` to `8`, one for each nested `Option`,
+/// `Result` or other `WantExtract` type
+///
+/// # Expansion
+///
+/// ```ignore
+/// deep_unwrap_or!($exp, 1, $do)
+/// // expands to
+/// unwrap_or_do!($exp, $do)
+///
+/// deep_unwrap_or!($exp, 2, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 1, $do)
+///
+/// deep_unwrap_or!($exp, 3, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 2, $do)
+///
+/// deep_unwrap_or!($exp, 4, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 3, $do)
+///
+/// deep_unwrap_or!($exp, 5, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 4, $do)
+///
+/// deep_unwrap_or!($exp, 6, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 5, $do)
+///
+/// deep_unwrap_or!($exp, 7, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 6, $do)
+///
+/// deep_unwrap_or!($exp, 8, $do)
+/// // expands to
+/// deep_unwrap_or!(unwrap_or_do!($exp, $do), 7, $do)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn innermost(value: Option<Result<Option<i32>, ()>>) -> i32 {
+///     deep_unwrap_or!(value, 3, return -1)
+/// }
+///
+/// assert_eq!(innermost(Some(Ok(Some(1)))), 1);
+/// assert_eq!(innermost(None), -1);
+/// assert_eq!(innermost(Some(Err(()))), -1);
+/// assert_eq!(innermost(Some(Ok(None))), -1);
+///
+/// let nested = vec![Some(Ok::<_, ()>(Some(1))), Some(Ok(None)), Some(Ok(Some(2)))];
+/// let mut found = Vec::new();
+/// for value in nested {
+///     found.push(deep_unwrap_or!(value, 3, continue));
+/// }
+/// assert_eq!(found, vec![1, 2]);
+/// ```
+macro_rules! deep_unwrap_or {
+    ($exp: expr, 1, $do: expr) => {
+        unwrap_or_do!($exp, $do)
+    };
+    ($exp: expr, 2, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 1, $do)
+    };
+    ($exp: expr, 3, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 2, $do)
+    };
+    ($exp: expr, 4, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 3, $do)
+    };
+    ($exp: expr, 5, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 4, $do)
+    };
+    ($exp: expr, 6, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 5, $do)
+    };
+    ($exp: expr, 7, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 6, $do)
+    };
+    ($exp: expr, 8, $do: expr) => {
+        deep_unwrap_or!(unwrap_or_do!($exp, $do), 7, $do)
+    };
+}
+
+#[macro_export]
+/// Receive a datagram from a nonblocking socket; if it would block, then do
+/// `continue`, and on any other error do `return Err(<error>)`
+///
+/// Works with anything that has `recv_from(&mut [u8]) -> io::Result<(usize, SocketAddr)>`,
+/// such as `std::net::UdpSocket`
+///
+/// # Expansion
+///
+/// ```ignore
+/// recv_or_continue!($socket, $buf)
+/// // expands to
+/// match $socket.recv_from($buf) {
+///     Ok(received) => received,
+///     Err(e) if e.kind() == ::std::io::ErrorKind::WouldBlock => continue,
+///     Err(e) => return Err(::std::convert::From::from(e)),
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use std::io;
+/// use std::net::SocketAddr;
+/// use what_i_want::*;
+///
+/// struct MockSocket {
+///     replies: VecDeque<io::Result<(usize, SocketAddr)>>,
+/// }
+///
+/// impl MockSocket {
+///     fn recv_from(&mut self, _buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+///         self.replies.pop_front().unwrap()
+///     }
+/// }
+///
+/// fn serve(socket: &mut MockSocket, received: &mut Vec<usize>) -> io::Result<()> {
+///     let mut buf = [0; 64];
+///     loop {
+///         let (len, _addr) = recv_or_continue!(socket, &mut buf);
+///         received.push(len);
+///     }
+/// }
+///
+/// let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+/// let mut socket = MockSocket {
+///     replies: vec![
+///         Err(io::ErrorKind::WouldBlock.into()),
+///         Ok((5, addr)),
+///         Err(io::ErrorKind::WouldBlock.into()),
+///         Err(io::ErrorKind::ConnectionReset.into()),
+///     ]
+///     .into_iter()
+///     .collect(),
+/// };
+/// let mut received = Vec::new();
+/// let err = serve(&mut socket, &mut received).unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+/// assert_eq!(received, vec![5]);
+/// ```
+macro_rules! recv_or_continue {
+    ($socket: expr, $buf: expr) => {
+        match $socket.recv_from($buf) {
+            Ok(received) => received,
+            Err(e) if e.kind() == ::std::io::ErrorKind::WouldBlock => continue,
+            Err(e) => return Err(::std::convert::From::from(e)),
+        }
+    };
+}
+
+#[macro_export]
+/// Make a `Result` that is what you want, see [`want_ok`](fn@want_ok)
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_ok!($value)
+/// // expands to
+/// $crate::want_ok($value)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let result: Result<i32, ()> = want_ok!(42);
+/// assert_eq!(result.is_i_want(), true);
+///
+/// fn double(result: Result<i32, ()>) -> i32 {
+///     unwrap_or_val!(result, -1) * 2
+/// }
+/// assert_eq!(double(want_ok!(21)), 42);
+/// ```
+macro_rules! want_ok {
+    ($value: expr) => {
+        $crate::want_ok($value)
+    };
+}
+
+#[macro_export]
+/// Make an `Option` that is what you want, see [`want_some`](fn@want_some)
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_some!($value)
+/// // expands to
+/// $crate::want_some($value)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(want_some!(42).is_i_want(), true);
+///
+/// let mut total = 0;
+/// for item in vec![want_some!(1), None, want_some!(2)] {
+///     total += unwrap_or_continue!(item);
+/// }
+/// assert_eq!(total, 3);
+/// ```
+macro_rules! want_some {
+    ($value: expr) => {
+        $crate::want_some($value)
+    };
+}
+
+#[macro_export]
+/// Make a `Result` that is not what you want, see [`want_err`](fn@want_err)
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_err!($error)
+/// // expands to
+/// $crate::want_err($error)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyError {
+///     Invalid,
+/// }
+///
+/// fn double(result: Result<i32, MyError>) -> Result<i32, MyError> {
+///     let value = context_unwrap_or_return!(result, |e| e);
+///     Ok(value * 2)
+/// }
+///
+/// assert_eq!(double(want_ok!(21)), Ok(42));
+/// assert_eq!(double(want_err!(MyError::Invalid)), Err(MyError::Invalid));
+/// ```
+macro_rules! want_err {
+    ($error: expr) => {
+        $crate::want_err($error)
+    };
+}
+
+#[macro_export]
+/// Make an `Option` that is not what you want, see [`want_none`](fn@want_none)
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_none!()
+/// // expands to
+/// $crate::want_none()
+///
+/// want_none!($ty)
+/// // expands to
+/// $crate::want_none::<$ty>()
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn double(option: Option<i32>) -> i32 {
+///     unwrap_or_val!(option, -1) * 2
+/// }
+///
+/// assert_eq!(double(want_some!(21)), 42);
+/// assert_eq!(double(want_none!()), -1);
+/// assert_eq!(want_none!(i32).is_i_want(), false);
+/// ```
+macro_rules! want_none {
+    () => {
+        $crate::want_none()
+    };
+    ($ty: ty) => {
+        $crate::want_none::<$ty>()
+    };
+}
+
+#[macro_export]
+/// Assert that it's what you want
+///
+/// # Expansion
+///
+/// ```ignore
+/// assert_want!($exp)
+/// // expands to
+/// assert!(
+///     $crate::WhatIwant::is_i_want(&$exp),
+///     concat!("assertion failed: `", stringify!($exp), "` is what I want")
+/// )
+///
+/// assert_want!($exp, $($arg)+)
+/// // expands to
+/// assert!($crate::WhatIwant::is_i_want(&$exp), $($arg)+)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_want!(Some(1));
+/// assert_want!(Ok::<i32, ()>(1), "custom message");
+///
+/// let panic = std::panic::catch_unwind(|| assert_want!(None::<i32>)).unwrap_err();
+/// assert_eq!(
+///     panic.downcast_ref::<&str>().unwrap(),
+///     &"assertion failed: `None::<i32>` is what I want"
+/// );
+/// ```
+macro_rules! assert_want {
+    ($exp: expr) => {
+        assert!(
+            $crate::WhatIwant::is_i_want(&$exp),
+            concat!("assertion failed: `", stringify!($exp), "` is what I want")
+        )
+    };
+    ($exp: expr, $($arg: tt)+) => {
+        assert!($crate::WhatIwant::is_i_want(&$exp), $($arg)+)
+    };
+}
+
+#[macro_export]
+/// Assert that it's not what you want
+///
+/// # Expansion
+///
+/// ```ignore
+/// assert_not_want!($exp)
+/// // expands to
+/// assert!(
+///     !$crate::WhatIwant::is_i_want(&$exp),
+///     concat!("assertion failed: `", stringify!($exp), "` is not what I want")
+/// )
+///
+/// assert_not_want!($exp, $($arg)+)
+/// // expands to
+/// assert!(!$crate::WhatIwant::is_i_want(&$exp), $($arg)+)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_not_want!(None::<i32>);
+/// assert_not_want!(Err::<i32, ()>(()), "custom message");
+///
+/// let panic = std::panic::catch_unwind(|| assert_not_want!(Some(1))).unwrap_err();
+/// assert_eq!(
+///     panic.downcast_ref::<&str>().unwrap(),
+///     &"assertion failed: `Some(1)` is not what I want"
+/// );
+/// ```
+macro_rules! assert_not_want {
+    ($exp: expr) => {
+        assert!(
+            !$crate::WhatIwant::is_i_want(&$exp),
+            concat!("assertion failed: `", stringify!($exp), "` is not what I want")
+        )
+    };
+    ($exp: expr, $($arg: tt)+) => {
+        assert!(!$crate::WhatIwant::is_i_want(&$exp), $($arg)+)
+    };
+}
+
+#[macro_export]
+/// Assert that it's what you want, only in debug builds
+///
+/// # Expansion
+///
+/// ```ignore
+/// debug_assert_want!($($arg)*)
+/// // expands to
+/// if cfg!(debug_assertions) {
+///     assert_want!($($arg)*);
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// debug_assert_want!(Some(1));
+///
+/// let result = std::panic::catch_unwind(|| debug_assert_want!(None::<i32>));
+/// assert_eq!(result.is_err(), cfg!(debug_assertions));
+/// ```
+macro_rules! debug_assert_want {
+    ($($arg: tt)*) => {
+        if cfg!(debug_assertions) {
+            assert_want!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+/// Assert that it's not what you want, only in debug builds
+///
+/// # Expansion
+///
+/// ```ignore
+/// debug_assert_not_want!($($arg)*)
+/// // expands to
+/// if cfg!(debug_assertions) {
+///     assert_not_want!($($arg)*);
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// debug_assert_not_want!(None::<i32>);
+///
+/// let result = std::panic::catch_unwind(|| debug_assert_not_want!(Some(1)));
+/// assert_eq!(result.is_err(), cfg!(debug_assertions));
+/// ```
+macro_rules! debug_assert_not_want {
+    ($($arg: tt)*) => {
+        if cfg!(debug_assertions) {
+            assert_not_want!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+/// Flatten a `Result<Result<T, E>, E>` into a `Result<T, E>`; given an action,
+/// if it's not what you want, then do the action
+///
+/// Both error types must be the same, unlike [`deep_unwrap_or!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// flatten_result_want!($exp)
+/// // expands to
+/// $exp.and_then(|inner| inner)
+///
+/// flatten_result_want!($exp, $do)
+/// // expands to
+/// unwrap_or_do!(flatten_result_want!($exp), $do)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use what_i_want::*;
+///
+/// fn open(name: &str) -> io::Result<io::Result<String>> {
+///     match name {
+///         "missing" => Err(io::ErrorKind::NotFound.into()),
+///         "binary" => Ok(Err(io::ErrorKind::InvalidData.into())),
+///         _ => Ok(Ok(format!("contents of {}", name))),
+///     }
+/// }
+///
+/// let flattened: io::Result<String> = flatten_result_want!(open("binary"));
+/// assert_eq!(flattened.unwrap_err().kind(), io::ErrorKind::InvalidData);
+///
+/// let mut contents = Vec::new();
+/// for name in &["a.txt", "missing", "binary", "b.txt"] {
+///     contents.push(flatten_result_want!(open(name), continue));
+/// }
+/// assert_eq!(contents, vec!["contents of a.txt", "contents of b.txt"]);
+/// ```
+macro_rules! flatten_result_want {
+    ($exp: expr) => {
+        $exp.and_then(|inner| inner)
+    };
+    ($exp: expr, $do: expr) => {
+        unwrap_or_do!(flatten_result_want!($exp), $do)
+    };
+}
+
+#[macro_export]
+/// Get `Some` of the first one that is what you want, or `None`
+///
+/// Like SQL's `COALESCE`, the ones after it are not evaluated
+///
+/// # Expansion
+///
+/// ```ignore
+/// first_wanted!($($exp),+ $(,)?)
+/// // expands to
+/// 'first_wanted: {
+///     $(
+///         if let Ok(want) = $crate::WantExtract::ok_or_reject($exp) {
+///             break 'first_wanted Some(want);
+///         }
+///     )+
+///     None
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut evaluated = Vec::new();
+/// let mut lookup = |name: &'static str, value: Option<i32>| {
+///     evaluated.push(name);
+///     value
+/// };
+///
+/// let first = first_wanted!(lookup("a", None), lookup("b", Some(2)), lookup("c", Some(3)));
+/// assert_eq!(first, Some(2));
+/// assert_eq!(evaluated, vec!["a", "b"]);
+///
+/// let none: Option<i32> = first_wanted!(None, "oops".parse::<i32>());
+/// assert_eq!(none, None);
+/// ```
+macro_rules! first_wanted {
+    ($($exp: expr),+ $(,)?) => {
+        'first_wanted: {
+            $(
+                if let Ok(want) = $crate::WantExtract::ok_or_reject($exp) {
+                    break 'first_wanted Some(want);
+                }
+            )+
+            None
+        }
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then use the default value, without returning
+///
+/// # Expansion
+///
+/// ```ignore
+/// or_default_want!($exp)
+/// // expands to
+/// unwrap_or_do!($exp, ::std::default::Default::default())
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let port: u16 = or_default_want!("oops".parse::<u16>());
+/// assert_eq!(port, 0);
+/// let name: String = or_default_want!(Some("admin".to_string()));
+/// assert_eq!(name, "admin");
+///
+/// let numbers: Vec<i32> = vec!["1", "oops", "3"]
+///     .into_iter()
+///     .map(|s| or_default_want!(s.parse::<i32>()))
+///     .collect();
+/// assert_eq!(numbers, vec![1, 0, 3]);
+/// ```
+macro_rules! or_default_want {
+    ($exp: expr) => {
+        unwrap_or_do!($exp, ::std::default::Default::default())
+    };
+}
+
+#[macro_export]
+/// Unwrap a `Result`, telling apart the errors that the predicate accepts
+///
+/// - `Ok(value)` evaluates to `value`
+/// - `Err(e)` where `pred(&e)` is `true` does what you want, e.g. `continue` to retry
+/// - `Err(e)` where `pred(&e)` is `false` does `return Err(e.into())`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_do_unless_err!($exp, $pred, $do)
+/// // expands to
+/// match $exp {
+///     Ok(value) => value,
+///     Err(e) => {
+///         if ($pred)(&e) {
+///             $do
+///         } else {
+///             return Err(::std::convert::From::from(e));
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use what_i_want::*;
+///
+/// fn is_retryable(e: &io::Error) -> bool {
+///     matches!(e.kind(), io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock)
+/// }
+///
+/// fn read_all(reads: Vec<io::Result<u8>>) -> io::Result<Vec<u8>> {
+///     let mut bytes = Vec::new();
+///     for read in reads {
+///         bytes.push(unwrap_or_do_unless_err!(read, is_retryable, continue));
+///     }
+///     Ok(bytes)
+/// }
+///
+/// let retryable = vec![
+///     Ok(1),
+///     Err(io::ErrorKind::Interrupted.into()),
+///     Err(io::ErrorKind::WouldBlock.into()),
+///     Ok(2),
+/// ];
+/// assert_eq!(read_all(retryable).unwrap(), vec![1, 2]);
+///
+/// let fatal = vec![Ok(1), Err(io::ErrorKind::PermissionDenied.into()), Ok(2)];
+/// assert_eq!(read_all(fatal).unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+/// ```
+macro_rules! unwrap_or_do_unless_err {
+    ($exp: expr, $pred: expr, $do: expr) => {
+        match $exp {
+            Ok(value) => value,
+            Err(e) => {
+                if ($pred)(&e) {
+                    $do
+                } else {
+                    return Err(::std::convert::From::from(e));
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Execute if the float is `Normal` (not zero, subnormal, infinite or NaN),
+/// otherwise return
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_normal_float!($value)
+/// // expands to
+/// require!($crate::WhatIwant::is_i_want(&($value).classify()))
+///
+/// require_normal_float!($value, $return)
+/// // expands to
+/// require!($crate::WhatIwant::is_i_want(&($value).classify()), $return)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn inverse(value: f64) -> Option<f64> {
+///     require_normal_float!(value, None);
+///     Some(1.0 / value)
+/// }
+///
+/// assert_eq!(inverse(2.0), Some(0.5));
+/// assert_eq!(inverse(0.0), None);
+/// assert_eq!(inverse(f64::MIN_POSITIVE / 2.0), None);
+/// assert_eq!(inverse(f64::INFINITY), None);
+/// assert_eq!(inverse(f64::NAN), None);
+/// ```
+macro_rules! require_normal_float {
+    ($value: expr) => {
+        require!($crate::WhatIwant::is_i_want(&($value).classify()))
+    };
+    ($value: expr, $return: expr) => {
+        require!($crate::WhatIwant::is_i_want(&($value).classify()), $return)
+    };
+}
+
+#[macro_export]
+/// If the poll is `Pending`, then do `return Poll::Pending`, otherwise get the
+/// `Ready` value
+///
+/// # Expansion
+///
+/// ```ignore
+/// ready_i_want!($poll)
+/// // expands to
+/// unwrap_or_do!($poll, return ::std::task::Poll::Pending)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll, Waker};
+/// use what_i_want::*;
+///
+/// struct Numbers {
+///     polls: Vec<Poll<Option<i32>>>,
+/// }
+///
+/// impl Numbers {
+///     fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<i32>> {
+///         self.polls.remove(0)
+///     }
+/// }
+///
+/// struct Doubled {
+///     inner: Numbers,
+/// }
+///
+/// impl Doubled {
+///     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<i32>> {
+///         let next = ready_i_want!(Pin::new(&mut self.inner).poll_next(cx));
+///         Poll::Ready(next.map(|n| n * 2))
+///     }
+/// }
+///
+/// let mut stream = Doubled {
+///     inner: Numbers {
+///         polls: vec![Poll::Pending, Poll::Ready(Some(1)), Poll::Ready(None)],
+///     },
+/// };
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+/// assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(2)));
+/// assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+/// ```
+macro_rules! ready_i_want {
+    ($poll: expr) => {
+        unwrap_or_do!($poll, return ::std::task::Poll::Pending)
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do `continue` for this and the next `n - 1`
+/// iterations
+///
+/// The skips left are kept in the given counter, declared before the loop as
+/// `let mut counter = 0;`, since a macro can't declare a variable that lives
+/// across iterations. The expression is not evaluated while skipping
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_skip_n!($exp, $n, $counter)
+/// // expands to
+/// if $counter > 0 {
+///     $counter -= 1;
+///     continue;
+/// } else {
+///     unwrap_or_do!($exp, {
+///         $counter = ($n as usize).saturating_sub(1);
+///         continue;
+///     })
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn decode(byte: u8) -> Option<u8> {
+///     if byte == 0xFF {
+///         None
+///     } else {
+///         Some(byte)
+///     }
+/// }
+///
+/// // A corrupt record starts with 0xFF and spans three bytes
+/// let stream = [1, 2, 0xFF, 7, 7, 3, 4];
+/// let mut skip = 0;
+/// let mut iterations = 0;
+/// let mut decoded = Vec::new();
+/// for &byte in &stream {
+///     iterations += 1;
+///     decoded.push(unwrap_or_skip_n!(decode(byte), 3, skip));
+/// }
+/// assert_eq!(iterations, 7);
+/// assert_eq!(decoded, vec![1, 2, 3, 4]);
+/// ```
+macro_rules! unwrap_or_skip_n {
+    ($exp: expr, $n: expr, $counter: ident) => {
+        if $counter > 0 {
+            $counter -= 1;
+            continue;
+        } else {
+            unwrap_or_do!($exp, {
+                $counter = ($n as usize).saturating_sub(1);
+                continue;
+            })
+        }
+    };
+}
+
+#[macro_export]
+/// Execute if the result is `Ok`, otherwise run the closure-like block with
+/// the error, which should diverge (e.g. `return`)
+///
+/// The closure-like block, `|pattern| body` or `|name: Type| body`, is
+/// expanded in place, so `return` returns from the function. A function given
+/// by its path is called with the error instead, and must diverge or give a
+/// value to use in place of the `Ok` one. Other closures are not accepted, so
+/// a `return` never changes meaning
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_ok_or_else!($res, |$e: $t| $body)
+/// // expands to
+/// match $res {
+///     Ok(value) => value,
+///     Err(e) => {
+///         let $e: $t = e;
+///         $body
+///     }
+/// }
+///
+/// require_ok_or_else!($res, |$e| $body)
+/// // expands to
+/// match $res {
+///     Ok(value) => value,
+///     Err($e) => $body,
+/// }
+///
+/// require_ok_or_else!($res, $f)
+/// // expands to
+/// match $res {
+///     Ok(value) => value,
+///     Err(e) => ($f)(e),
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn parse(input: &str, log: &mut Vec<String>) -> i32 {
+///     let number = require_ok_or_else!(input.parse::<i32>(), |e| {
+///         log.push(format!("{}: {}", input, e));
+///         return -1;
+///     });
+///     number * 2
+/// }
+///
+/// let mut log = Vec::new();
+/// assert_eq!(parse("2", &mut log), 4);
+/// assert!(log.is_empty());
+/// assert_eq!(parse("oops", &mut log), -1);
+/// assert_eq!(log, vec!["oops: invalid digit found in string"]);
+///
+/// fn parse_typed(input: &str) -> i32 {
+///     let number = require_ok_or_else!(input.parse::<i32>(), |e: std::num::ParseIntError| {
+///         assert_eq!(e.to_string(), "invalid digit found in string");
+///         return -1;
+///     });
+///     number + 100
+/// }
+///
+/// assert_eq!(parse_typed("2"), 102);
+/// assert_eq!(parse_typed("oops"), -1);
+///
+/// fn or_zero(_: std::num::ParseIntError) -> i32 {
+///     0
+/// }
+///
+/// let pairs: Vec<(i32, i32)> = vec![Ok((1, 2)), Err((3, 4))]
+///     .into_iter()
+///     .map(|res: Result<(i32, i32), (i32, i32)>| require_ok_or_else!(res, |(a, b)| (b, a)))
+///     .collect();
+/// assert_eq!(pairs, vec![(1, 2), (4, 3)]);
+/// assert_eq!(require_ok_or_else!("x".parse::<i32>(), or_zero), 0);
+/// ```
+macro_rules! require_ok_or_else {
+    ($res: expr, |$e: ident: $t: ty| $body: expr) => {
+        match $res {
+            Ok(value) => value,
+            Err(e) => {
+                let $e: $t = e;
+                $body
+            }
+        }
+    };
+    ($res: expr, |$e: pat| $body: expr) => {
+        match $res {
+            Ok(value) => value,
+            Err($e) => $body,
+        }
+    };
+    ($res: expr, $f: path) => {
+        match $res {
+            Ok(value) => value,
+            Err(e) => ($f)(e),
+        }
+    };
+}
+
+#[macro_export]
+/// Get the next item of the iterator; if it's exhausted, then do `break`
+///
+/// # Expansion
+///
+/// ```ignore
+/// next_or_break!($iter)
+/// // expands to
+/// unwrap_or_do!($iter.next(), break)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut range = 0..3;
+/// let mut drained = Vec::new();
+/// loop {
+///     drained.push(next_or_break!(range));
+/// }
+/// assert_eq!(drained, vec![0, 1, 2]);
+/// ```
+macro_rules! next_or_break {
+    ($iter: expr) => {
+        unwrap_or_do!($iter.next(), break)
+    };
+}
+
+#[macro_export]
+/// Get the next item of the iterator; if it's exhausted, then return
+///
+/// # Expansion
+///
+/// ```ignore
+/// next_or_return!($iter)
+/// // expands to
+/// unwrap_or_do!($iter.next(), return)
+///
+/// next_or_return!($iter, $return)
+/// // expands to
+/// unwrap_or_do!($iter.next(), return $return)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn sum(mut range: std::ops::Range<i32>) -> i32 {
+///     let mut total = 0;
+///     loop {
+///         total += next_or_return!(range, total);
+///     }
+/// }
+///
+/// fn drain(range: &mut std::ops::Range<i32>, drained: &mut Vec<i32>) {
+///     loop {
+///         drained.push(next_or_return!(range));
+///     }
+/// }
+///
+/// assert_eq!(sum(0..4), 6);
+/// let mut drained = Vec::new();
+/// drain(&mut (0..3), &mut drained);
+/// assert_eq!(drained, vec![0, 1, 2]);
+/// ```
+macro_rules! next_or_return {
+    ($iter: expr) => {
+        unwrap_or_do!($iter.next(), return)
+    };
+    ($iter: expr, $return: expr) => {
+        unwrap_or_do!($iter.next(), return $return)
+    };
+}
+
+#[macro_export]
+/// Get `&mut` of the cached value; if the `Option` is `None`, initialize it
+/// with the closure, and if that's not what you want, then do `continue`
+///
+/// The closure is only called when the `Option` is `None`
+///
+/// # Expansion
+///
+/// ```ignore
+/// get_or_init_continue!($opt, $init)
+/// // expands to
+/// match $opt {
+///     opt => {
+///         if opt.is_none() {
+///             let value = unwrap_or_continue!(($init)());
+///             *opt = Some(value);
+///         }
+///         match opt {
+///             Some(value) => value,
+///             None => unreachable!(),
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut cache: Option<Vec<i32>> = None;
+/// let mut attempts = 0;
+/// for input in &["oops", "2", "3"] {
+///     let cached = get_or_init_continue!(&mut cache, || {
+///         attempts += 1;
+///         input.parse::<i32>().map(|n| vec![n])
+///     });
+///     cached.push(0);
+/// }
+/// assert_eq!(attempts, 2);
+/// assert_eq!(cache, Some(vec![2, 0, 0]));
+/// ```
+macro_rules! get_or_init_continue {
+    ($opt: expr, $init: expr) => {
+        match $opt {
+            opt => {
+                if opt.is_none() {
+                    let value = unwrap_or_continue!(($init)());
+                    *opt = Some(value);
+                }
+                match opt {
+                    Some(value) => value,
+                    None => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Get what you want; if it's not what you want, then panic with the message
+///
+/// Without a message, the template set by [`set_default_expect_message`] is used
+///
+/// Its `{reason}` is what was rejected, described the same way as in the
+/// logging macros: the [`reject_reason`](WhatIwant::reject_reason), else
+/// `value not present` for an `Option`, else the error's `Display` if it has
+/// one, else its `Debug`
+///
+/// # Expansion
+///
+/// ```ignore
+/// expect_i_want!($exp)
+/// // expands to
+/// match $exp {
+///     want => {
+///         let reason = $crate::reject_reason_of(&want);
+///         match $crate::WantExtract::ok_or_reject(want) {
+///             Ok(want) => want,
+///             Err(rejected) => panic!(
+///                 "{}",
+///                 $crate::default_expect_message(&$crate::__describe_rejected!(
+///                     reason, rejected
+///                 ))
+///             ),
+///         }
+///     }
+/// }
+///
+/// expect_i_want!($exp, $msg)
+/// // expands to
+/// unwrap_or_do!($exp, panic!("{}", $msg))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(expect_i_want!(Some(1)), 1);
+/// assert_eq!(expect_i_want!("2".parse::<i32>(), "a number is required"), 2);
+///
+/// let panic = std::panic::catch_unwind(|| expect_i_want!(None::<i32>, "an item is required"))
+///     .unwrap_err();
+/// assert_eq!(panic.downcast_ref::<String>().unwrap(), "an item is required");
+///
+/// let panic = std::panic::catch_unwind(|| expect_i_want!(NoError(Some("disk full".into()))))
+///     .unwrap_err();
+/// assert_eq!(panic.downcast_ref::<String>().unwrap(), "not what I want: disk full");
+///
+/// let panic = std::panic::catch_unwind(|| expect_i_want!("x".parse::<i32>())).unwrap_err();
+/// assert_eq!(
+///     panic.downcast_ref::<String>().unwrap(),
+///     "not what I want: invalid digit found in string"
+/// );
+/// ```
+macro_rules! expect_i_want {
+    ($exp: expr) => {
+        match $exp {
+            want => {
+                let reason = $crate::reject_reason_of(&want);
+                match $crate::WantExtract::ok_or_reject(want) {
+                    Ok(want) => want,
+                    Err(rejected) => panic!(
+                        "{}",
+                        $crate::default_expect_message(&$crate::__describe_rejected!(
+                            reason, rejected
+                        ))
+                    ),
+                }
+            }
+        }
+    };
+    ($exp: expr, $msg: expr) => {
+        unwrap_or_do!($exp, panic!("{}", $msg))
+    };
+}
+
+#[macro_export]
+/// Execute if there are no duplicates in the slice or collection, otherwise
+/// return
+///
+/// The items must be `Eq + Hash`. The collection is iterated by reference, so
+/// an owned one can still be used after the check
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_distinct!($items)
+/// // expands to
+/// require!({
+///     let mut seen = ::std::collections::HashSet::new();
+///     (&$items).into_iter().all(|item| seen.insert(item))
+/// })
+///
+/// require_distinct!($items, $return)
+/// // expands to
+/// require!(
+///     {
+///         let mut seen = ::std::collections::HashSet::new();
+///         (&$items).into_iter().all(|item| seen.insert(item))
+///     },
+///     $return
+/// )
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn distinct_ids(ids: &[i32]) -> bool {
+///     require_distinct!(ids, false);
+///     true
+/// }
+///
+/// fn distinct_names(names: Vec<&str>, checked: &mut bool) {
+///     require_distinct!(names);
+///     *checked = true;
+/// }
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Key(u8);
+///
+/// fn distinct_keys(keys: &[Key]) -> Option<usize> {
+///     require_distinct!(keys, None);
+///     Some(keys.len())
+/// }
+///
+/// assert!(distinct_ids(&[1, 2, 3]));
+/// assert!(!distinct_ids(&[1, 2, 1]));
+///
+/// let mut checked = false;
+/// distinct_names(vec!["a", "b", "a"], &mut checked);
+/// assert!(!checked);
+/// distinct_names(vec!["a", "b"], &mut checked);
+/// assert!(checked);
+///
+/// assert_eq!(distinct_keys(&[Key(1), Key(2)]), Some(2));
+/// assert_eq!(distinct_keys(&[Key(1), Key(1)]), None);
+///
+/// fn count_tags(tags: Vec<String>) -> usize {
+///     require_distinct!(tags, 0);
+///     tags.len()
+/// }
+///
+/// assert_eq!(count_tags(vec!["a".into(), "b".into()]), 2);
+/// assert_eq!(count_tags(vec!["a".into(), "a".into()]), 0);
+/// ```
+macro_rules! require_distinct {
+    ($items: expr) => {
+        require!({
+            let mut seen = ::std::collections::HashSet::new();
+            (&$items).into_iter().all(|item| seen.insert(item))
+        })
+    };
+    ($items: expr, $return: expr) => {
+        require!(
+            {
+                let mut seen = ::std::collections::HashSet::new();
+                (&$items).into_iter().all(|item| seen.insert(item))
+            },
+            $return
+        )
+    };
+}
+
+#[macro_export]
+/// Get the value of an `Ok(Some(value))`; if it's `Err` or `Ok(None)`, then return
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_ok_some!($exp)
+/// // expands to
+/// match $exp {
+///     Ok(Some(value)) => value,
+///     _ => return,
+/// }
+///
+/// require_ok_some!($exp; $return)
+/// // expands to
+/// match $exp {
+///     Ok(Some(value)) => value,
+///     _ => return $return,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn find_user(id: i32) -> Result<Option<String>, String> {
+///     match id {
+///         1 => Ok(Some("admin".to_string())),
+///         2 => Ok(None),
+///         _ => Err("connection lost".to_string()),
+///     }
+/// }
+///
+/// fn greet(id: i32) -> String {
+///     let name = require_ok_some!(find_user(id); "nobody".to_string());
+///     format!("hello {}", name)
+/// }
+///
+/// fn touch(id: i32, touched: &mut Vec<String>) {
+///     let name = require_ok_some!(find_user(id));
+///     touched.push(name);
+/// }
+///
+/// assert_eq!(greet(1), "hello admin");
+/// assert_eq!(greet(2), "nobody");
+/// assert_eq!(greet(3), "nobody");
+///
+/// let mut touched = Vec::new();
+/// for id in 1..=3 {
+///     touch(id, &mut touched);
+/// }
+/// assert_eq!(touched, vec!["admin"]);
+/// ```
+macro_rules! require_ok_some {
+    ($exp: expr) => {
+        match $exp {
+            Ok(Some(value)) => value,
+            _ => return,
+        }
+    };
+    ($exp: expr; $return: expr) => {
+        match $exp {
+            Ok(Some(value)) => value,
+            _ => return $return,
+        }
+    };
+}
+
+#[macro_export]
+/// Get what you want; if it's not what you want, then panic in debug builds,
+/// and do the fallback in release builds
+///
+/// The panic message shows what was rejected with `Debug`. The fallback is
+/// needed because release builds still have to handle the rejection, just
+/// like [`unwrap_or_do!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// debug_assert_wanted!($exp, $fallback)
+/// // expands to
+/// match $crate::WantExtract::ok_or_reject($exp) {
+///     Ok(want) => want,
+///     Err(rejected) => {
+///         if cfg!(debug_assertions) {
+///             panic!("`{}` is not what I want: {:?}", stringify!($exp), rejected);
+///         }
+///         $fallback
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn parse(input: &str) -> i32 {
+///     debug_assert_wanted!(input.parse::<i32>(), return -1)
+/// }
+///
+/// assert_eq!(parse("2"), 2);
+///
+/// let result = std::panic::catch_unwind(|| parse("oops"));
+/// if cfg!(debug_assertions) {
+///     let panic = result.unwrap_err();
+///     let message = panic.downcast_ref::<String>().unwrap();
+///     assert!(message.contains("InvalidDigit"));
+/// } else {
+///     assert_eq!(result.unwrap(), -1);
+/// }
+/// ```
+macro_rules! debug_assert_wanted {
+    ($exp: expr, $fallback: expr) => {
+        match $crate::WantExtract::ok_or_reject($exp) {
+            Ok(want) => want,
+            Err(rejected) => {
+                if cfg!(debug_assertions) {
+                    panic!("`{}` is not what I want: {:?}", stringify!($exp), rejected);
+                }
+                $fallback
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Execute if the collection can hold `n` more items without reallocating,
+/// otherwise return
+///
+/// The collection expression is evaluated once
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_capacity_for!($collection, $n)
+/// // expands to
+/// {
+///     let collection = &$collection;
+///     require!(collection.capacity() - collection.len() >= $n);
+/// }
+///
+/// require_capacity_for!($collection, $n, $return)
+/// // expands to
+/// {
+///     let collection = &$collection;
+///     require!(collection.capacity() - collection.len() >= $n, $return);
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn push_all(vec: &mut Vec<i32>, items: &[i32]) -> bool {
+///     require_capacity_for!(vec, items.len(), false);
+///     vec.extend_from_slice(items);
+///     true
+/// }
+///
+/// let mut vec = Vec::with_capacity(3);
+/// vec.push(1);
+/// vec.push(2);
+/// assert!(!push_all(&mut vec, &[3, 4]));
+/// assert_eq!(vec.capacity() - vec.len(), 1);
+/// assert!(push_all(&mut vec, &[3]));
+/// assert_eq!(vec, vec![1, 2, 3]);
+///
+/// fn has_room(queues: &[Vec<i32>], evaluated: &mut i32) -> bool {
+///     require_capacity_for!(
+///         {
+///             *evaluated += 1;
+///             &queues[0]
+///         },
+///         1,
+///         false
+///     );
+///     true
+/// }
+///
+/// let mut evaluated = 0;
+/// assert!(has_room(&[Vec::with_capacity(1)], &mut evaluated));
+/// assert_eq!(evaluated, 1);
+/// ```
+macro_rules! require_capacity_for {
+    ($collection: expr, $n: expr) => {{
+        let collection = &$collection;
+        require!(collection.capacity() - collection.len() >= $n);
+    }};
+    ($collection: expr, $n: expr, $return: expr) => {{
+        let collection = &$collection;
+        require!(collection.capacity() - collection.len() >= $n, $return);
+    }};
+}
+
+#[macro_export]
+/// Get the value if it's within `min..=max`; if it's out of range, then do
+/// what you want (`return` by default)
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_clamp!($value, $min, $max)
+/// // expands to
+/// want_clamp!($value, $min, $max, return)
+///
+/// want_clamp!($value, $min, $max, $do)
+/// // expands to
+/// match $value {
+///     value => {
+///         if $min <= value && value <= $max {
+///             value
+///         } else {
+///             $do
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn percent(value: i32) -> Option<i32> {
+///     let value = want_clamp!(value, 0, 100, return None);
+///     Some(value)
+/// }
+///
+/// assert_eq!(percent(0), Some(0));
+/// assert_eq!(percent(100), Some(100));
+/// assert_eq!(percent(-1), None);
+/// assert_eq!(percent(101), None);
+///
+/// let mut accepted = Vec::new();
+/// for value in vec![-1, 0, 50, 100, 101] {
+///     accepted.push(want_clamp!(value, 0, 100, continue));
+/// }
+/// assert_eq!(accepted, vec![0, 50, 100]);
+/// ```
+macro_rules! want_clamp {
+    ($value: expr, $min: expr, $max: expr) => {
+        want_clamp!($value, $min, $max, return)
+    };
+    ($value: expr, $min: expr, $max: expr, $do: expr) => {
+        match $value {
+            value => {
+                if $min <= value && value <= $max {
+                    value
+                } else {
+                    $do
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "anyhow")]
+#[macro_export]
+/// If it's not what you want, then do `return Err(<anyhow::Error>)`
+///
+/// The error of a `Result` is kept, anything else becomes a message, see
+/// [`WantAnyhow`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_anyhow!($exp)
+/// // expands to
+/// match $crate::WantAnyhow::ok_or_anyhow($exp) {
+///     Ok(want) => want,
+///     Err(e) => return Err(::std::convert::From::from(e)),
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn load(port: &str, host: Option<&str>) -> anyhow::Result<String> {
+///     let port = unwrap_or_anyhow!(port.parse::<u16>());
+///     let host = unwrap_or_anyhow!(host);
+///     Ok(format!("{}:{}", host, port))
+/// }
+///
+/// assert_eq!(load("80", Some("localhost")).unwrap(), "localhost:80");
+/// assert_eq!(
+///     load("oops", Some("localhost")).unwrap_err().to_string(),
+///     "invalid digit found in string"
+/// );
+/// assert_eq!(load("80", None).unwrap_err().to_string(), "value not present");
+/// ```
+macro_rules! unwrap_or_anyhow {
+    ($exp: expr) => {
+        match $crate::WantAnyhow::ok_or_anyhow($exp) {
+            Ok(want) => want,
+            Err(e) => return Err(::std::convert::From::from(e)),
+        }
+    };
+}
+
+#[macro_export]
+/// If it's what you want, then call `$f` with it and get `Some(<result>)`,
+/// otherwise get `None`
+///
+/// # Expansion
+///
+/// ```ignore
+/// run_if_want!($exp, $f)
+/// // expands to
+/// match $crate::WantExtract::ok_or_reject($exp) {
+///     Ok(want) => Some(($f)(want)),
+///     Err(_) => None,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(run_if_want!("42".parse::<i32>(), |v| v * 2), Some(84));
+///
+/// let mut called = false;
+/// assert_eq!(run_if_want!("oops".parse::<i32>(), |v| { called = true; v }), None);
+/// assert!(!called);
+///
+/// // The closure can return an `Option` to keep chaining
+/// let half = run_if_want!(Some(7), |v: i32| if v % 2 == 0 { Some(v / 2) } else { None });
+/// assert_eq!(half, Some(None));
+/// assert_eq!(half.flatten(), None);
+/// ```
+macro_rules! run_if_want {
+    ($exp: expr, $f: expr) => {
+        match $crate::WantExtract::ok_or_reject($exp) {
+            Ok(want) => Some(($f)(want)),
+            Err(_) => None,
+        }
+    };
+}
+
+#[macro_export]
+/// Run a block in an immediately-invoked closure, so the `return` of the
+/// other macros (and `?`) leaves the block instead of the enclosing function
+///
+/// The block evaluates to whatever it returns, usually a `Result` or an `Option`
+///
+/// # Expansion
+///
+/// ```ignore
+/// try_block!($block)
+/// // expands to
+/// (|| $block)()
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let pairs = vec!["1,2", "3", "x,4", "5,6"];
+/// let sums: Vec<Option<i32>> = pairs
+///     .iter()
+///     .map(|pair| try_block!({
+///         let mut parts = pair.split(',');
+///         let a = unwrap_or_none!(parts.next());
+///         let b = unwrap_or_none!(parts.next());
+///         let a = unwrap_or_do!(a.parse::<i32>(), return None);
+///         Some(a + b.parse::<i32>().ok()?)
+///     }))
+///     .collect();
+/// assert_eq!(sums, vec![Some(3), None, None, Some(11)]);
+///
+/// let parsed: Vec<Result<u8, String>> = vec!["7", "300", "0"]
+///     .into_iter()
+///     .map(|s| try_block!({
+///         let n = unwrap_or_do!(s.parse::<u8>(), return Err(format!("bad input: {}", s)));
+///         require!(n > 0, Err("zero".to_string()));
+///         Ok(n)
+///     }))
+///     .collect();
+/// assert_eq!(
+///     parsed,
+///     vec![Ok(7), Err("bad input: 300".to_string()), Err("zero".to_string())]
+/// );
+/// ```
+macro_rules! try_block {
+    ($block: block) => {
+        (|| $block)()
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, or what you want was already taken once, then
+/// do `continue`
+///
+/// Whether it was taken is kept in the given flag, declared before the loop as
+/// `let mut done = false;`, since a macro can't declare a variable that lives
+/// across iterations. The expression is not evaluated once it's taken
+///
+/// # Expansion
+///
+/// ```ignore
+/// once_or_continue!($exp, $flag)
+/// // expands to
+/// if $flag {
+///     continue;
+/// } else {
+///     let want = unwrap_or_do!($exp, continue);
+///     $flag = true;
+///     want
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut done = false;
+/// let mut runs = Vec::new();
+/// for value in vec![None, Some(1), Some(2), None] {
+///     let value = once_or_continue!(value, done);
+///     runs.push(value);
+/// }
+/// assert_eq!(runs, vec![1]);
+/// assert!(done);
+/// ```
+macro_rules! once_or_continue {
+    ($exp: expr, $flag: ident) => {
+        if $flag {
+            continue;
+        } else {
+            let want = unwrap_or_do!($exp, continue);
+            $flag = true;
+            want
+        }
+    };
+}
+
+#[cfg(feature = "log")]
+#[macro_export]
+/// If it's not what you want, then log it as an error and do
+/// `return Err(<rejected>)`, like `?` with logging
+///
+/// The rejected value is converted with `From`, so an `Option` returns
+/// `Err(())`; give a return value to return something else instead
+///
+/// The message is the [`reject_reason`](WhatIwant::reject_reason) when there
+/// is one, else what was rejected formatted with `Display` if it can be, else
+/// with `Debug`, the same as [`ok_or_log_default!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// ok_or_log_err!($exp)
+/// // expands to
+/// match $exp {
+///     want => {
+///         let reason = $crate::reject_reason_of(&want);
+///         match $crate::WantExtract::ok_or_reject(want) {
+///             Ok(want) => want,
+///             Err(rejected) => {
+///                 $crate::log::error!(
+///                     "not what I want: {}",
+///                     $crate::__describe_rejected!(reason, rejected)
+///                 );
+///                 return Err(::std::convert::From::from(rejected));
+///             }
+///         }
+///     }
+/// }
+///
+/// ok_or_log_err!($exp, $return)
+/// // expands to
+/// match $exp {
+///     want => {
+///         let reason = $crate::reject_reason_of(&want);
+///         match $crate::WantExtract::ok_or_reject(want) {
+///             Ok(want) => want,
+///             Err(rejected) => {
+///                 $crate::log::error!(
+///                     "not what I want: {}",
+///                     $crate::__describe_rejected!(reason, rejected)
+///                 );
+///                 return $return;
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+/// use what_i_want::*;
+///
+/// static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// struct TestLogger;
+///
+/// impl log::Log for TestLogger {
+///     fn enabled(&self, _: &log::Metadata) -> bool {
+///         true
+///     }
+///
+///     fn log(&self, record: &log::Record) {
+///         LOGGED
+///             .lock()
+///             .unwrap()
+///             .push(format!("{} {}", record.level(), record.args()));
+///     }
+///
+///     fn flush(&self) {}
+/// }
+///
+/// log::set_logger(&TestLogger).unwrap();
+/// log::set_max_level(log::LevelFilter::Error);
+///
+/// fn double(input: &str) -> Result<i32, std::num::ParseIntError> {
+///     let n = ok_or_log_err!(input.parse::<i32>());
+///     Ok(n * 2)
+/// }
+///
+/// fn first_char(input: &str) -> Option<char> {
+///     Some(ok_or_log_err!(input.chars().next(), None))
+/// }
+///
+/// assert_eq!(double("2"), Ok(4));
+/// assert!(LOGGED.lock().unwrap().is_empty());
+///
+/// assert!(double("oops").is_err());
+/// assert_eq!(first_char(""), None);
+/// let logged = LOGGED.lock().unwrap();
+/// assert_eq!(logged.len(), 2);
+/// assert_eq!(logged[0], "ERROR not what I want: invalid digit found in string");
+/// assert_eq!(logged[1], "ERROR not what I want: value not present");
+/// ```
+///
+/// A [`reject_reason`](WhatIwant::reject_reason) is logged instead of what
+/// was rejected
+///
+/// ```
+/// use std::sync::Mutex;
+/// use what_i_want::*;
+///
+/// static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// struct TestLogger;
+///
+/// impl log::Log for TestLogger {
+///     fn enabled(&self, _: &log::Metadata) -> bool {
+///         true
+///     }
+///
+///     fn log(&self, record: &log::Record) {
+///         LOGGED.lock().unwrap().push(record.args().to_string());
+///     }
+///
+///     fn flush(&self) {}
+/// }
+///
+/// struct Port(u32);
+///
+/// impl WhatIwant for Port {
+///     fn is_i_want(&self) -> bool {
+///         self.0 <= 65535
+///     }
+///
+///     fn reject_reason(&self) -> Option<String> {
+///         Some(format!("port {} is out of range", self.0))
+///     }
+/// }
+///
+/// impl WantExtract for Port {
+///     type Output = u16;
+///     type Rejected = u32;
+///
+///     fn ok_or_reject(self) -> Result<u16, u32> {
+///         if self.is_i_want() {
+///             Ok(self.0 as u16)
+///         } else {
+///             Err(self.0)
+///         }
+///     }
+/// }
+///
+/// log::set_logger(&TestLogger).unwrap();
+/// log::set_max_level(log::LevelFilter::Error);
+///
+/// fn listen(port: u32) -> Result<u16, u32> {
+///     Ok(ok_or_log_err!(Port(port)))
+/// }
+///
+/// assert_eq!(listen(70000), Err(70000));
+/// assert_eq!(LOGGED.lock().unwrap()[0], "not what I want: port 70000 is out of range");
+/// ```
+macro_rules! ok_or_log_err {
+    ($exp: expr) => {
+        match $exp {
+            want => {
+                let reason = $crate::reject_reason_of(&want);
+                match $crate::WantExtract::ok_or_reject(want) {
+                    Ok(want) => want,
+                    Err(rejected) => {
+                        $crate::log::error!(
+                            "not what I want: {}",
+                            $crate::__describe_rejected!(reason, rejected)
+                        );
+                        return Err(::std::convert::From::from(rejected));
+                    }
+                }
+            }
+        }
+    };
+    ($exp: expr, $return: expr) => {
+        match $exp {
+            want => {
+                let reason = $crate::reject_reason_of(&want);
+                match $crate::WantExtract::ok_or_reject(want) {
+                    Ok(want) => want,
+                    Err(rejected) => {
+                        $crate::log::error!(
+                            "not what I want: {}",
+                            $crate::__describe_rejected!(reason, rejected)
+                        );
+                        return $return;
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "log")]
+#[macro_export]
+/// Get what you want, or log what was rejected as an error and get the default
+/// value
+///
+/// Like [`ok_or_log_default`](WhatIwantExt::ok_or_log_default), but what was
+/// rejected is described the same way as in [`ok_or_log_err!`], so a
+/// `Result`'s error is logged with `Display` when it has one
+///
+/// # Expansion
+///
+/// ```ignore
+/// ok_or_log_default!($exp)
+/// // expands to
+/// match $exp {
+///     want => {
+///         let reason = $crate::reject_reason_of(&want);
+///         match $crate::WantExtract::ok_or_reject(want) {
+///             Ok(want) => want,
+///             Err(rejected) => {
+///                 $crate::log::error!(
+///                     "not what I want: {}",
+///                     $crate::__describe_rejected!(reason, rejected)
+///                 );
+///                 ::std::default::Default::default()
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+/// use what_i_want::*;
+///
+/// static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// struct TestLogger;
+///
+/// impl log::Log for TestLogger {
+///     fn enabled(&self, _: &log::Metadata) -> bool {
+///         true
+///     }
+///
+///     fn log(&self, record: &log::Record) {
+///         LOGGED.lock().unwrap().push(record.args().to_string());
+///     }
+///
+///     fn flush(&self) {}
+/// }
+///
+/// log::set_logger(&TestLogger).unwrap();
+/// log::set_max_level(log::LevelFilter::Error);
+///
+/// #[derive(Debug)]
+/// enum MyErr {
+///     Missing,
+/// }
+///
+/// assert_eq!(ok_or_log_default!("2".parse::<i32>()), 2);
+/// assert!(LOGGED.lock().unwrap().is_empty());
+///
+/// assert_eq!(ok_or_log_default!("oops".parse::<i32>()), 0);
+/// assert_eq!(ok_or_log_default!(None::<String>), "");
+/// assert_eq!(ok_or_log_default!(Err::<i32, _>(MyErr::Missing)), 0);
+/// assert_eq!(
+///     *LOGGED.lock().unwrap(),
+///     [
+///         "not what I want: invalid digit found in string",
+///         "not what I want: value not present",
+///         "not what I want: Missing",
+///     ]
+/// );
+/// ```
+macro_rules! ok_or_log_default {
+    ($exp: expr) => {
+        match $exp {
+            want => {
+                let reason = $crate::reject_reason_of(&want);
+                match $crate::WantExtract::ok_or_reject(want) {
+                    Ok(want) => want,
+                    Err(rejected) => {
+                        $crate::log::error!(
+                            "not what I want: {}",
+                            $crate::__describe_rejected!(reason, rejected)
+                        );
+                        ::std::default::Default::default()
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// If any of them is not what you want, then do `continue`, otherwise bind
+/// what you want out of each to its name
+///
+/// The expressions are evaluated in order, and the ones after the first
+/// rejected one are not evaluated
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_all_or_continue_named!($($name = $exp),+ $(,)?)
+/// // expands to
+/// $(
+///     let $name = unwrap_or_do!($exp, continue);
+/// )+
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let rows = vec![
+///     ("1", Some('a'), true),
+///     ("x", Some('b'), true),
+///     ("3", None, true),
+///     ("4", Some('d'), false),
+/// ];
+/// let mut seen = Vec::new();
+/// for (id, tag, active) in rows {
+///     unwrap_all_or_continue_named!(
+///         id = id.parse::<u32>(),
+///         tag = tag,
+///         active = Some(active).filter(|&active| active),
+///     );
+///     let (id, tag, active): (u32, char, bool) = (id, tag, active);
+///     seen.push((id, tag, active));
+/// }
+/// assert_eq!(seen, vec![(1, 'a', true)]);
+/// ```
+macro_rules! unwrap_all_or_continue_named {
+    ($($name: ident = $exp: expr),+ $(,)?) => {
+        $(
+            let $name = unwrap_or_do!($exp, continue);
+        )+
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then do ..., and hand how long getting it took
+/// to the observer either way
+///
+/// The observer is called with the `Duration` before `$do` runs
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_timed!($exp, $do)
+/// // expands to
+/// unwrap_or_do!($exp, $do)
+///
+/// unwrap_or_timed!($exp, $observer, $do)
+/// // expands to
+/// match ::std::time::Instant::now() {
+///     start => {
+///         let result = $crate::WantExtract::ok_or_reject($exp);
+///         ($observer)(start.elapsed());
+///         match result {
+///             Ok(want) => want,
+///             Err(_) => $do,
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use what_i_want::*;
+///
+/// fn slow_parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     std::thread::sleep(Duration::from_millis(1));
+///     s.parse()
+/// }
+///
+/// let mut timings = Vec::new();
+/// let mut parsed = Vec::new();
+/// for s in &["1", "oops", "3"] {
+///     parsed.push(unwrap_or_timed!(slow_parse(s), |took| timings.push(took), continue));
+/// }
+/// assert_eq!(parsed, vec![1, 3]);
+/// assert_eq!(timings.len(), 3);
+/// assert!(timings.iter().all(|&took| took >= Duration::from_millis(1)));
+///
+/// // Without an observer, it's the same as `unwrap_or_do!`
+/// assert_eq!(unwrap_or_timed!(None::<i32>, 0), 0);
+/// ```
+macro_rules! unwrap_or_timed {
+    ($exp: expr, $do: expr) => {
+        unwrap_or_do!($exp, $do)
+    };
+    ($exp: expr, $observer: expr, $do: expr) => {
+        match ::std::time::Instant::now() {
+            start => {
+                let result = $crate::WantExtract::ok_or_reject($exp);
+                ($observer)(start.elapsed());
+                match result {
+                    Ok(want) => want,
+                    Err(_) => $do,
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Require the condition at compile time, the build fails if it's false
+///
+/// Unlike [`require!`], nothing is returned at runtime: the condition is
+/// checked in a `const` block, so it can only use constants, const generics
+/// and `const fn`s. Inside generic functions the check happens when the
+/// function is instantiated, so a function that's never called is never
+/// checked
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_const!($condition)
+/// // expands to
+/// const { assert!($condition) }
+///
+/// require_const!($condition, $message)
+/// // expands to
+/// const { assert!($condition, $message) }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// const SIZE: usize = 4;
+///
+/// fn chunks<const N: usize>(data: &[u8]) -> std::slice::ChunksExact<'_, u8> {
+///     require_const!(N > 0, "chunk size must not be zero");
+///     data.chunks_exact(N)
+/// }
+///
+/// require_const!(SIZE.is_power_of_two());
+/// assert_eq!(chunks::<SIZE>(&[0; 8]).count(), 2);
+/// ```
+///
+/// ```compile_fail
+/// use what_i_want::*;
+///
+/// fn chunks<const N: usize>(data: &[u8]) -> std::slice::ChunksExact<'_, u8> {
+///     require_const!(N > 0);
+///     data.chunks_exact(N)
+/// }
+///
+/// chunks::<0>(&[0; 8]);
+/// ```
+macro_rules! require_const {
+    ($condition: expr) => {
+        const { assert!($condition) }
+    };
+    ($condition: expr, $message: expr) => {
+        const { assert!($condition, $message) }
+    };
+}
+
+#[macro_export]
+/// If the `Result<(), E>` is not `Ok(())`, then return
+///
+/// Same as [`require!`] on `is_ok()`, but only takes a [`WantResult0`], so it
+/// can't drop a value by mistake
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_ok_unit!($exp)
+/// // expands to
+/// match $exp {
+///     Ok(()) => {}
+///     Err(_) => return,
+/// }
+///
+/// require_ok_unit!($exp, $return)
+/// // expands to
+/// match $exp {
+///     Ok(()) => {}
+///     Err(_) => return $return,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn non_empty(s: &str) -> WantResult0<&'static str> {
+///     if s.is_empty() { Err("empty") } else { Ok(()) }
+/// }
+///
+/// fn ascii(s: &str) -> WantResult0<&'static str> {
+///     if s.is_ascii() { Ok(()) } else { Err("not ascii") }
+/// }
+///
+/// fn short(s: &str) -> WantResult0<&'static str> {
+///     if s.len() <= 8 { Ok(()) } else { Err("too long") }
+/// }
+///
+/// fn valid_username(s: &str) -> bool {
+///     require_ok_unit!(non_empty(s), false);
+///     require_ok_unit!(ascii(s), false);
+///     require_ok_unit!(short(s), false);
+///     true
+/// }
+///
+/// assert!(valid_username("admin"));
+/// assert!(!valid_username(""));
+/// assert!(!valid_username("ädmin"));
+/// assert!(!valid_username("administrator"));
+///
+/// let mut checked = 0;
+/// let mut check = |s: &str| {
+///     require_ok_unit!(non_empty(s));
+///     checked += 1;
+/// };
+/// check("");
+/// check("root");
+/// assert_eq!(checked, 1);
+/// ```
+macro_rules! require_ok_unit {
+    ($exp: expr) => {
+        match $exp {
+            Ok(()) => {}
+            Err(_) => return,
+        }
+    };
+    ($exp: expr, $return: expr) => {
+        match $exp {
+            Ok(()) => {}
+            Err(_) => return $return,
+        }
+    };
+}
+
+#[macro_export]
+/// Require the condition at compile time as an item, the build fails if it's
+/// false
+///
+/// Unlike [`require_const!`], it can be used outside of functions, e.g. at
+/// the module level to check the configuration, but it can't use generics
+///
+/// # Expansion
+///
+/// ```ignore
+/// static_require!($condition)
+/// // expands to
+/// const _: () = assert!($condition);
+///
+/// static_require!($condition, $message)
+/// // expands to
+/// const _: () = assert!($condition, $message);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// const WORKERS: usize = 4;
+/// const QUEUE_SIZE: usize = 64;
+///
+/// static_require!(WORKERS > 0);
+/// static_require!(QUEUE_SIZE % WORKERS == 0, "the queue must split evenly");
+///
+/// mod config {
+///     what_i_want::static_require!(std::mem::size_of::<usize>() >= 4);
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use what_i_want::*;
+///
+/// const WORKERS: usize = 0;
+///
+/// static_require!(WORKERS > 0, "at least one worker is needed");
+/// ```
+///
+/// ```compile_fail
+/// use what_i_want::*;
+///
+/// const WORKERS: usize = 3;
+/// const QUEUE_SIZE: usize = 64;
+///
+/// static_require!(QUEUE_SIZE % WORKERS == 0);
+/// ```
+macro_rules! static_require {
+    ($condition: expr) => {
+        const _: () = assert!($condition);
+    };
+    ($condition: expr, $message: expr) => {
+        const _: () = assert!($condition, $message);
+    };
+}
+
+#[cfg(feature = "log")]
+#[macro_export]
+/// If it's not what you want, then log the stage it failed at as an error and
+/// do `return Err(<rejected>)`, like [`ok_or_log_err!`] with a stage name
+///
+/// What was rejected is described the same way as in [`ok_or_log_err!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// checkpoint!($stage, $exp)
+/// // expands to
+/// match $exp {
+///     want => {
+///         let reason = $crate::reject_reason_of(&want);
+///         match $crate::WantExtract::ok_or_reject(want) {
+///             Ok(want) => want,
+///             Err(rejected) => {
+///                 $crate::log::error!(
+///                     "failed at {}: {}",
+///                     $stage,
+///                     $crate::__describe_rejected!(reason, rejected)
+///                 );
+///                 return Err(::std::convert::From::from(rejected));
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::sync::Mutex;
+/// use what_i_want::*;
+///
+/// static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// struct TestLogger;
+///
+/// impl log::Log for TestLogger {
+///     fn enabled(&self, _: &log::Metadata) -> bool {
+///         true
+///     }
+///
+///     fn log(&self, record: &log::Record) {
+///         LOGGED.lock().unwrap().push(record.args().to_string());
+///     }
+///
+///     fn flush(&self) {}
+/// }
+///
+/// log::set_logger(&TestLogger).unwrap();
+/// log::set_max_level(log::LevelFilter::Error);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ImportError {
+///     Parse,
+///     Range,
+/// }
+///
+/// fn import(input: &str) -> Result<u8, ImportError> {
+///     let number = checkpoint!("parse", input.parse::<i32>().map_err(|_| ImportError::Parse));
+///     let byte = checkpoint!("range", u8::try_from(number).map_err(|_| ImportError::Range));
+///     Ok(byte)
+/// }
+///
+/// assert_eq!(import("7"), Ok(7));
+/// assert!(LOGGED.lock().unwrap().is_empty());
+///
+/// assert_eq!(import("x"), Err(ImportError::Parse));
+/// assert_eq!(import("300"), Err(ImportError::Range));
+///
+/// fn count(input: &str) -> Result<usize, std::num::ParseIntError> {
+///     Ok(checkpoint!("count", input.parse::<usize>()))
+/// }
+///
+/// assert!(count("-1").is_err());
+/// let logged = LOGGED.lock().unwrap();
+/// assert_eq!(logged[0], "failed at parse: Parse");
+/// assert_eq!(logged[1], "failed at range: Range");
+/// assert_eq!(logged[2], "failed at count: invalid digit found in string");
+/// ```
+macro_rules! checkpoint {
+    ($stage: expr, $exp: expr) => {
+        match $exp {
+            want => {
+                let reason = $crate::reject_reason_of(&want);
+                match $crate::WantExtract::ok_or_reject(want) {
+                    Ok(want) => want,
+                    Err(rejected) => {
+                        $crate::log::error!(
+                            "failed at {}: {}",
+                            $stage,
+                            $crate::__describe_rejected!(reason, rejected)
+                        );
+                        return Err(::std::convert::From::from(rejected));
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// If it's not what you want, then await the cleanup and return
+///
+/// Only the wanted value is kept while the cleanup is awaited, what was
+/// rejected is dropped before, so it doesn't need to be `Send` for the
+/// future to be `Send`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_await_cleanup!($exp, $cleanup)
+/// // expands to
+/// unwrap_or_await_cleanup!($exp, $cleanup, ())
+///
+/// unwrap_or_await_cleanup!($exp, $cleanup, $return)
+/// // expands to
+/// {
+///     let want = $crate::WantExtract::ok_or_reject($exp).ok();
+///     match want {
+///         Some(want) => want,
+///         None => {
+///             $cleanup.await;
+///             return $return;
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use std::rc::Rc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use what_i_want::*;
+///
+/// static CLEANUPS: AtomicUsize = AtomicUsize::new(0);
+///
+/// async fn release() {
+///     CLEANUPS.fetch_add(1, Ordering::SeqCst);
+/// }
+///
+/// async fn fetch(id: i32) -> Result<i32, Rc<String>> {
+///     if id > 0 {
+///         Ok(id * 10)
+///     } else {
+///         Err(Rc::new(format!("no item {}", id)))
+///     }
+/// }
+///
+/// async fn handle(id: i32) -> i32 {
+///     let item = unwrap_or_await_cleanup!(fetch(id).await, release(), -1);
+///     release().await;
+///     item
+/// }
+///
+/// fn assert_send<F: std::future::Future + Send>(future: F) -> F {
+///     future
+/// }
+///
+/// async fn log_only(id: i32) {
+///     let item = unwrap_or_await_cleanup!(Some(id).filter(|&id| id > 0), async {});
+///     println!("{}", item);
+/// }
+///
+/// assert_eq!(block_on(handle(2)), 20);
+/// assert_eq!(block_on(assert_send(handle(-1))), -1);
+/// assert_eq!(CLEANUPS.load(Ordering::SeqCst), 2);
+/// block_on(assert_send(log_only(0)));
+/// ```
+macro_rules! unwrap_or_await_cleanup {
+    ($exp: expr, $cleanup: expr) => {
+        unwrap_or_await_cleanup!($exp, $cleanup, ())
+    };
+    ($exp: expr, $cleanup: expr, $return: expr) => {{
+        let want = $crate::WantExtract::ok_or_reject($exp).ok();
+        match want {
+            Some(want) => want,
+            None => {
+                $cleanup.await;
+                return $return;
+            }
+        }
+    }};
+}
+
+#[macro_export]
+/// Get each step of the macro call's expansion as text, without evaluating it
+///
+/// The macros built on [`unwrap_or_do!`] are followed down to its `match`,
+/// each step is written as `<call> => <what it expands to>`. Other macro calls
+/// are not expanded, their only step is the call itself. [`expand_want!`]
+/// prints these steps
+///
+/// # Expansion
+///
+/// ```ignore
+/// want_expansion!(unwrap_or_continue!($exp))
+/// // expands to
+/// want_expansion!(@step unwrap_or_continue!($exp) => unwrap_or_do!($exp, continue))
+///
+/// want_expansion!(@step $name!($($from)*) => $next!($($to)*))
+/// // expands to
+/// {
+///     let mut steps = vec![concat!(
+///         stringify!($name!($($from)*)),
+///         " => ",
+///         stringify!($next!($($to)*))
+///     )];
+///     steps.extend(want_expansion!($next!($($to)*)));
+///     steps
+/// }
+///
+/// want_expansion!(unwrap_or_do!($exp, $do))
+/// // expands to
+/// vec![concat!(
+///     stringify!(unwrap_or_do!($exp, $do)),
+///     " => ",
+///     stringify!(match what_i_want::WantExtract::ok_or_reject($exp) {
+///         Ok(want) => want,
+///         Err(_) => $do,
+///     })
+/// )]
+///
+/// want_expansion!($name!($($args)*))
+/// // expands to
+/// vec![stringify!($name!($($args)*))]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let steps: Vec<String> = want_expansion!(unwrap_or_continue!(item))
+///     .into_iter()
+///     .map(|step| step.split_whitespace().collect())
+///     .collect();
+/// assert_eq!(
+///     steps,
+///     [
+///         "unwrap_or_continue!(item)=>unwrap_or_do!(item,continue)",
+///         "unwrap_or_do!(item,continue)=>matchwhat_i_want::WantExtract::ok_or_reject(item){Ok(want)=>want,Err(_)=>continue,}",
+///     ]
+/// );
+///
+/// assert_eq!(want_expansion!(want_ok!(1)).len(), 1);
+/// ```
+macro_rules! want_expansion {
+    (unwrap_or_do!($exp: expr, $do: expr)) => {
+        vec![concat!(
+            stringify!(unwrap_or_do!($exp, $do)),
+            " => ",
+            stringify!(match what_i_want::WantExtract::ok_or_reject($exp) {
+                Ok(want) => want,
+                Err(_) => $do,
+            })
+        )]
+    };
+    (unwrap_or_continue!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_continue!($exp) => unwrap_or_do!($exp, continue))
+    };
+    (unwrap_or_break!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_break!($exp) => unwrap_or_do!($exp, break))
+    };
+    (extract_or_continue!($exp: expr)) => {
+        want_expansion!(@step extract_or_continue!($exp) => unwrap_or_continue!($exp))
+    };
+    (unwrap_or_return!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_return!($exp) => unwrap_or_do!($exp, return))
+    };
+    (unwrap_or_false!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_false!($exp) => unwrap_or_do!($exp, return false))
+    };
+    (unwrap_or_true!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_true!($exp) => unwrap_or_do!($exp, return true))
+    };
+    (unwrap_or_val!($exp: expr, $val: expr)) => {
+        want_expansion!(@step unwrap_or_val!($exp, $val) => unwrap_or_do!($exp, return $val))
+    };
+    (some_try!($exp: expr)) => {
+        want_expansion!(@step some_try!($exp) => unwrap_or_do!($exp, return None))
+    };
+    (unwrap_or_none!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_none!($exp) => unwrap_or_do!($exp, return None))
+    };
+    (unwrap_or_return_none!($exp: expr)) => {
+        want_expansion!(@step unwrap_or_return_none!($exp) => unwrap_or_none!($exp))
+    };
+    (or_default_want!($exp: expr)) => {
+        want_expansion!(@step or_default_want!($exp) =>
+            unwrap_or_do!($exp, ::std::default::Default::default()))
+    };
+    (next_or_break!($iter: expr)) => {
+        want_expansion!(@step next_or_break!($iter) => unwrap_or_do!($iter.next(), break))
+    };
+    (@step $name: ident!($($from: tt)*) => $next: ident!($($to: tt)*)) => {{
+        let mut steps = vec![concat!(
+            stringify!($name!($($from)*)),
+            " => ",
+            stringify!($next!($($to)*))
+        )];
+        steps.extend(want_expansion!($next!($($to)*)));
+        steps
+    }};
+    ($name: ident!($($args: tt)*)) => {
+        vec![stringify!($name!($($args)*))]
+    };
+}
+
+#[macro_export]
+/// Evaluate the macro call, printing each step of its expansion to stderr
+///
+/// The steps are the ones given by [`want_expansion!`], one per line. The
+/// output is for debugging, see the `Expansion` section of each macro for the
+/// full story
+///
+/// # Expansion
+///
+/// ```ignore
+/// expand_want!($name!($($args)*))
+/// // expands to
+/// {
+///     for step in want_expansion!($name!($($args)*)) {
+///         eprintln!("{}", step);
+///     }
+///     $name!($($args)*)
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// // Prints something like
+/// // unwrap_or_continue!(item) => unwrap_or_do!(item, continue)
+/// // unwrap_or_do!(item, continue) => match ...
+/// let mut sum = 0;
+/// for item in vec![Some(1), None, Some(3)] {
+///     sum += expand_want!(unwrap_or_continue!(item));
+/// }
+/// assert_eq!(sum, 4);
+///
+/// fn parse(s: &str) -> Option<i32> {
+///     Some(expand_want!(unwrap_or_none!(s.parse::<i32>())) * 2)
+/// }
+/// assert_eq!(parse("2"), Some(4));
+/// assert_eq!(parse("x"), None);
+///
+/// assert_eq!(expand_want!(want_ok!(1)), Ok::<i32, ()>(1));
+/// ```
+macro_rules! expand_want {
+    ($name: ident!($($args: tt)*)) => {{
+        for step in want_expansion!($name!($($args)*)) {
+            eprintln!("{}", step);
+        }
+        $name!($($args)*)
+    }};
+}
+
+#[macro_export]
+/// If it's what you want, then move the state to the next one and give what
+/// you want, otherwise return and leave the state as it is
+///
+/// # Expansion
+///
+/// ```ignore
+/// transition_or_return!($state, $exp, $next)
+/// // expands to
+/// {
+///     let want = unwrap_or_do!($exp, return);
+///     $state = $next;
+///     want
+/// }
+///
+/// transition_or_return!($state, $exp, $next, $return)
+/// // expands to
+/// {
+///     let want = unwrap_or_do!($exp, return $return);
+///     $state = $next;
+///     want
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Conn {
+///     Idle,
+///     Connected,
+///     Authenticated,
+/// }
+///
+/// struct Client {
+///     state: Conn,
+///     user: Option<String>,
+/// }
+///
+/// impl Client {
+///     fn connect(&mut self, addr: &str) -> bool {
+///         let _port = transition_or_return!(
+///             self.state,
+///             addr.rsplit(':').next().and_then(|p| p.parse::<u16>().ok()),
+///             Conn::Connected,
+///             false
+///         );
+///         true
+///     }
+///
+///     fn login(&mut self, token: Option<&str>) {
+///         let user = transition_or_return!(self.state, token, Conn::Authenticated);
+///         self.user = Some(user.to_string());
+///     }
+/// }
+///
+/// let mut client = Client { state: Conn::Idle, user: None };
+/// assert!(!client.connect("localhost:http"));
+/// assert_eq!(client.state, Conn::Idle);
+/// assert!(client.connect("localhost:80"));
+/// assert_eq!(client.state, Conn::Connected);
+///
+/// client.login(None);
+/// assert_eq!(client.state, Conn::Connected);
+/// client.login(Some("admin"));
+/// assert_eq!(client.state, Conn::Authenticated);
+/// assert_eq!(client.user.as_deref(), Some("admin"));
+/// ```
+macro_rules! transition_or_return {
+    ($state: expr, $exp: expr, $next: expr) => {{
+        let want = unwrap_or_do!($exp, return);
+        $state = $next;
+        want
+    }};
+    ($state: expr, $exp: expr, $next: expr, $return: expr) => {{
+        let want = unwrap_or_do!($exp, return $return);
+        $state = $next;
+        want
+    }};
+}
+
+#[macro_export]
+/// If the index is out of bounds, then do `continue`, otherwise give a
+/// reference to the element
+///
+/// # Expansion
+///
+/// ```ignore
+/// get_or_continue!($slice, $index)
+/// // expands to
+/// unwrap_or_do!($slice.get($index), continue)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let values = [10, 20, 30];
+/// let mut picked = Vec::new();
+/// for i in vec![0, 5, 2, 3] {
+///     let value: &i32 = get_or_continue!(values, i);
+///     picked.push(*value);
+/// }
+/// assert_eq!(picked, vec![10, 30]);
+/// ```
+macro_rules! get_or_continue {
+    ($slice: expr, $index: expr) => {
+        unwrap_or_do!($slice.get($index), continue)
+    };
+}
+
+#[macro_export]
+/// If the index is out of bounds, then do `continue`, otherwise give a
+/// mutable reference to the element
+///
+/// # Expansion
+///
+/// ```ignore
+/// get_mut_or_continue!($slice, $index)
+/// // expands to
+/// unwrap_or_do!($slice.get_mut($index), continue)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut values = vec![10, 20, 30];
+/// for i in vec![0, 5, 2, 3] {
+///     let value: &mut i32 = get_mut_or_continue!(values, i);
+///     *value += 1;
+/// }
+/// assert_eq!(values, vec![11, 20, 31]);
+/// ```
+macro_rules! get_mut_or_continue {
+    ($slice: expr, $index: expr) => {
+        unwrap_or_do!($slice.get_mut($index), continue)
+    };
+}
+
+#[macro_export]
+/// Feed the input through the stages in order, each one getting what the
+/// previous one wanted, and give `Ok(<what the last one wanted>)`
+///
+/// The first stage that is not what you want stops the pipeline with
+/// `Err(<rejected>)`, converted with `From`, so later stages are not run
+///
+/// # Expansion
+///
+/// ```ignore
+/// pipeline!($input $(=> $stage)+)
+/// // expands to
+/// 'pipeline: {
+///     let value = $input;
+///     $(
+///         let value = match $crate::WantExtract::ok_or_reject(($stage)(value)) {
+///             Ok(want) => want,
+///             Err(rejected) => break 'pipeline Err(::std::convert::From::from(rejected)),
+///         };
+///     )+
+///     Ok(value)
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     Parse,
+///     Negative,
+/// }
+///
+/// fn parse(s: &str) -> Result<i32, Error> {
+///     s.trim().parse().map_err(|_| Error::Parse)
+/// }
+///
+/// fn non_negative(n: i32) -> Result<u32, Error> {
+///     if n < 0 { Err(Error::Negative) } else { Ok(n as u32) }
+/// }
+///
+/// let mut doubled = 0;
+/// let mut double = |n: u32| {
+///     doubled += 1;
+///     Ok::<_, Error>(n * 2)
+/// };
+///
+/// let result: Result<u32, Error> = pipeline!(" 21 " => parse => non_negative => &mut double);
+/// assert_eq!(result, Ok(42));
+///
+/// let result: Result<u32, Error> = pipeline!("-1" => parse => non_negative => &mut double);
+/// assert_eq!(result, Err(Error::Negative));
+/// assert_eq!(doubled, 1);
+///
+/// // The rejections are converted, so stages can be mixed
+/// let result: Result<char, String> = pipeline!(
+///     "hello"
+///         => |s: &str| s.chars().nth(1).ok_or("too short".to_string())
+///         => |c: char| if c.is_alphabetic() { Ok(c) } else { Err("not a letter") }
+/// );
+/// assert_eq!(result, Ok('e'));
+/// ```
+macro_rules! pipeline {
+    ($input: expr $(=> $stage: expr)+) => {
+        'pipeline: {
+            let value = $input;
+            $(
+                let value = match $crate::WantExtract::ok_or_reject(($stage)(value)) {
+                    Ok(want) => want,
+                    Err(rejected) => break 'pipeline Err(::std::convert::From::from(rejected)),
+                };
+            )+
+            Ok(value)
+        }
+    };
+}
+
+#[macro_export]
+/// Iterate over what you want out of the collection, without importing
+/// [`IteratorExt`]
+///
+/// Same as `collection.into_iter().map_wanted()`
+///
+/// # Expansion
+///
+/// ```ignore
+/// into_iter_want!($collection)
+/// // expands to
+/// $crate::IteratorExt::map_wanted(::std::iter::IntoIterator::into_iter($collection))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// let names = vec![Some("admin".to_string()), None, Some("root".to_string()), None];
+///
+/// let wanted: Vec<String> = what_i_want::into_iter_want!(names.clone()).collect();
+/// let manual: Vec<String> = names.into_iter().filter_map(|name| name).collect();
+/// assert_eq!(wanted, manual);
+/// assert_eq!(wanted, vec!["admin", "root"]);
+///
+/// let parsed: Vec<Result<i32, std::num::ParseIntError>> =
+///     vec!["1", "x", "3"].into_iter().map(str::parse).collect();
+/// let sum: i32 = what_i_want::into_iter_want!(parsed).sum();
+/// assert_eq!(sum, 4);
+/// ```
+macro_rules! into_iter_want {
+    ($collection: expr) => {
+        $crate::IteratorExt::map_wanted(::std::iter::IntoIterator::into_iter($collection))
+    };
+}
+
+#[cfg(feature = "tracing")]
+#[macro_export]
+/// If it's not what you want, then record it as an error event in the
+/// current span and do ...
+///
+/// The event is emitted with the current span as its parent, so traces show
+/// where the value was rejected
+///
+/// What was rejected is described the same way as in `ok_or_log_err!`
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_trace!($exp, $do)
+/// // expands to
+/// match $exp {
+///     want => {
+///         let reason = $crate::reject_reason_of(&want);
+///         match $crate::WantExtract::ok_or_reject(want) {
+///             Ok(want) => want,
+///             Err(rejected) => {
+///                 $crate::tracing::error!(
+///                     parent: &$crate::tracing::Span::current(),
+///                     "not what I want: {}",
+///                     $crate::__describe_rejected!(reason, rejected)
+///                 );
+///                 $do
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Write};
+/// use std::sync::{Arc, Mutex};
+/// use what_i_want::*;
+///
+/// #[derive(Clone, Default)]
+/// struct Captured(Arc<Mutex<Vec<u8>>>);
+///
+/// impl Write for Captured {
+///     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+///         self.0.lock().unwrap().write(buf)
+///     }
+///
+///     fn flush(&mut self) -> io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// fn handle(id: u32, input: &str) -> i32 {
+///     let span = tracing::info_span!("request", id);
+///     let _entered = span.enter();
+///     unwrap_or_trace!(input.parse::<i32>(), -1)
+/// }
+///
+/// let captured = Captured::default();
+/// let writer = captured.clone();
+/// let subscriber = tracing_subscriber::fmt()
+///     .with_ansi(false)
+///     .with_writer(move || writer.clone())
+///     .finish();
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     assert_eq!(handle(1, "2"), 2);
+///     assert_eq!(handle(7, "oops"), -1);
+/// });
+///
+/// let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+/// assert_eq!(output.lines().count(), 1);
+/// assert!(output.contains("ERROR request{id=7}"));
+/// assert!(output.contains("not what I want: invalid digit found in string"));
+/// ```
+macro_rules! unwrap_or_trace {
+    ($exp: expr, $do: expr) => {
+        match $exp {
+            want => {
+                let reason = $crate::reject_reason_of(&want);
+                match $crate::WantExtract::ok_or_reject(want) {
+                    Ok(want) => want,
+                    Err(rejected) => {
+                        $crate::tracing::error!(
+                            parent: &$crate::tracing::Span::current(),
+                            "not what I want: {}",
+                            $crate::__describe_rejected!(reason, rejected)
+                        );
+                        $do
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "tracing")]
+#[macro_export]
+/// If it's not what you want, then record it in the current span and do
+/// `continue`, see [`unwrap_or_trace!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_trace_continue!($exp)
+/// // expands to
+/// unwrap_or_trace!($exp, continue)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut parsed = Vec::new();
+/// for s in &["1", "x", "3"] {
+///     parsed.push(unwrap_or_trace_continue!(s.parse::<i32>()));
+/// }
+/// assert_eq!(parsed, vec![1, 3]);
+/// ```
+macro_rules! unwrap_or_trace_continue {
+    ($exp: expr) => {
+        unwrap_or_trace!($exp, continue)
+    };
+}
+
+#[cfg(feature = "tracing")]
+#[macro_export]
+/// If it's not what you want, then record it in the current span and
+/// return, see [`unwrap_or_trace!`]
+///
+/// # Expansion
+///
+/// ```ignore
+/// unwrap_or_trace_return!($exp)
+/// // expands to
+/// unwrap_or_trace!($exp, return)
+///
+/// unwrap_or_trace_return!($exp, $return)
+/// // expands to
+/// unwrap_or_trace!($exp, return $return)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn double(s: &str) -> Option<i32> {
+///     let n = unwrap_or_trace_return!(s.parse::<i32>(), None);
+///     Some(n * 2)
+/// }
+///
+/// assert_eq!(double("2"), Some(4));
+/// assert_eq!(double("x"), None);
+/// ```
+macro_rules! unwrap_or_trace_return {
+    ($exp: expr) => {
+        unwrap_or_trace!($exp, return)
+    };
+    ($exp: expr, $return: expr) => {
+        unwrap_or_trace!($exp, return $return)
+    };
+}
+
+#[macro_export]
+/// Execute if all the conditions are true, otherwise return the value paired
+/// with the first one that is false
+///
+/// Each `(condition, return value)` pair is a two-argument [`require!`], checked
+/// in order
+///
+/// # Expansion
+///
+/// ```ignore
+/// batch_require!($(($condition, $return)),+ $(,)?)
+/// // expands to
+/// $(
+///     require!($condition, $return);
+/// )+
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// struct SignUp {
+///     name: String,
+///     email: String,
+///     age: i32,
+///     password: String,
+///     accepted_terms: bool,
+/// }
+///
+/// fn validate(form: &SignUp) -> Result<(), &'static str> {
+///     batch_require!(
+///         (!form.name.is_empty(), Err("name empty")),
+///         (form.email.contains('@'), Err("email invalid")),
+///         (form.age >= 0, Err("age negative")),
+///         (form.password.len() >= 8, Err("password too short")),
+///         (form.accepted_terms, Err("terms not accepted")),
+///     );
+///     Ok(())
+/// }
+///
+/// let valid = || SignUp {
+///     name: "admin".into(),
+///     email: "admin@example.com".into(),
+///     age: 30,
+///     password: "correct horse".into(),
+///     accepted_terms: true,
+/// };
+/// assert_eq!(validate(&valid()), Ok(()));
+/// assert_eq!(validate(&SignUp { name: "".into(), ..valid() }), Err("name empty"));
+/// assert_eq!(validate(&SignUp { email: "admin".into(), ..valid() }), Err("email invalid"));
+/// assert_eq!(validate(&SignUp { age: -1, ..valid() }), Err("age negative"));
+/// assert_eq!(validate(&SignUp { password: "1234".into(), ..valid() }), Err("password too short"));
+/// assert_eq!(validate(&SignUp { accepted_terms: false, ..valid() }), Err("terms not accepted"));
+/// assert_eq!(validate(&SignUp { name: "".into(), age: -1, ..valid() }), Err("name empty"));
+/// ```
+macro_rules! batch_require {
+    ($(($condition: expr, $return: expr)),+ $(,)?) => {
+        $(
+            require!($condition, $return);
+        )+
+    };
+}
+
+#[macro_export]
+/// If any of them is not what you want, then do `return Err(<all rejected>)`
+///
+/// Unlike [`unwrap_all_or_continue_named!`], every expression is evaluated,
+/// so all of what was rejected is collected into a `Vec`, in order. What you
+/// want is dropped, nothing is bound
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_all_ok!($($exp),+ $(,)?)
+/// // expands to
+/// {
+///     let mut rejected = ::std::vec::Vec::new();
+///     $(
+///         if let Err(e) = $crate::WantExtract::ok_or_reject($exp) {
+///             rejected.push(e);
+///         }
+///     )+
+///     if !rejected.is_empty() {
+///         return Err(::std::convert::From::from(rejected));
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn check_name(name: &str) -> Result<(), String> {
+///     if name.is_empty() { Err("name empty".into()) } else { Ok(()) }
+/// }
+///
+/// fn check_age(age: i32) -> Result<(), String> {
+///     if age < 0 { Err(format!("age {} negative", age)) } else { Ok(()) }
+/// }
+///
+/// fn validate(name: &str, age: i32, port: &str) -> Result<(), Vec<String>> {
+///     require_all_ok!(
+///         check_name(name),
+///         check_age(age),
+///         port.parse::<u16>().map_err(|e| format!("port: {}", e)),
+///     );
+///     Ok(())
+/// }
+///
+/// assert_eq!(validate("admin", 30, "80"), Ok(()));
+/// assert_eq!(
+///     validate("", 30, "http"),
+///     Err(vec!["name empty".to_string(), "port: invalid digit found in string".to_string()])
+/// );
+/// assert_eq!(validate("admin", -1, "80"), Err(vec!["age -1 negative".to_string()]));
+/// ```
+macro_rules! require_all_ok {
+    ($($exp: expr),+ $(,)?) => {{
+        let mut rejected = ::std::vec::Vec::new();
+        $(
+            if let Err(e) = $crate::WantExtract::ok_or_reject($exp) {
+                rejected.push(e);
+            }
+        )+
+        if !rejected.is_empty() {
+            return Err(::std::convert::From::from(rejected));
+        }
+    }};
+}
+
+#[macro_export]
+/// If the `Option` cache is empty, then run the block and store what it
+/// gives, and give a mutable reference to what's cached either way
+///
+/// The block is not a closure, so it can borrow `self` even when the cache is
+/// a field of `self`, and it runs at most once while the cache stays filled
+///
+/// # Expansion
+///
+/// ```ignore
+/// memoize_or_compute!($cache, $compute)
+/// // expands to
+/// {
+///     if !$crate::WhatIwant::is_i_want(&$cache) {
+///         let value = $compute;
+///         $cache = Some(value);
+///     }
+///     match &mut $cache {
+///         Some(value) => value,
+///         None => unreachable!(),
+///     }
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// struct Report {
+///     rows: Vec<i32>,
+///     computed: usize,
+///     total: Option<i32>,
+/// }
+///
+/// impl Report {
+///     fn expensive_total(&mut self) -> i32 {
+///         self.computed += 1;
+///         self.rows.iter().sum()
+///     }
+///
+///     fn total(&mut self) -> i32 {
+///         *memoize_or_compute!(self.total, { self.expensive_total() })
+///     }
+/// }
+///
+/// let mut report = Report { rows: vec![1, 2, 3], computed: 0, total: None };
+/// assert_eq!(report.total(), 6);
+/// assert_eq!(report.total(), 6);
+/// assert_eq!(report.computed, 1);
+///
+/// report.rows.push(4);
+/// report.total = None;
+/// assert_eq!(report.total(), 10);
+/// assert_eq!(report.computed, 2);
+/// ```
+macro_rules! memoize_or_compute {
+    ($cache: expr, $compute: expr) => {{
+        if !$crate::WhatIwant::is_i_want(&$cache) {
+            let value = $compute;
+            $cache = Some(value);
+        }
+        match &mut $cache {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }};
+}
+
+#[macro_export]
+/// Parse the string as the type; if it doesn't parse, then do `continue`
+///
+/// Give what to do instead as the third argument
+///
+/// # Expansion
+///
+/// ```ignore
+/// try_parse!($s, $ty)
+/// // expands to
+/// unwrap_or_do!($crate::WantParsed::<$ty>::new($s), continue)
+///
+/// try_parse!($s, $ty, $do)
+/// // expands to
+/// unwrap_or_do!($crate::WantParsed::<$ty>::new($s), $do)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut numbers = Vec::new();
+/// for s in vec!["1", "two", "3", "", "-4"] {
+///     numbers.push(try_parse!(s, i32));
+/// }
+/// assert_eq!(numbers, vec![1, 3, -4]);
+///
+/// fn half(s: &str) -> f64 {
+///     try_parse!(s, f64, return f64::NAN) / 2.0
+/// }
+/// assert_eq!(half("3"), 1.5);
+/// assert!(half("three").is_nan());
+/// ```
+macro_rules! try_parse {
+    ($s: expr, $ty: ty) => {
+        unwrap_or_do!($crate::WantParsed::<$ty>::new($s), continue)
+    };
+    ($s: expr, $ty: ty, $do: expr) => {
+        unwrap_or_do!($crate::WantParsed::<$ty>::new($s), $do)
+    };
+}
+
+#[macro_export]
+/// Execute if the value is inside the range, otherwise return
+///
+/// The value is only borrowed, so it can still be used afterwards; see
+/// [`WantRangeCheck`] to get the value out instead
+///
+/// # Expansion
+///
+/// ```ignore
+/// require_in_range!($value, $range)
+/// // expands to
+/// require!(::std::ops::RangeBounds::contains(&$range, &$value))
+///
+/// require_in_range!($value, $range, $return)
+/// // expands to
+/// require!(::std::ops::RangeBounds::contains(&$range, &$value), $return)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn percent(value: i32) -> Option<i32> {
+///     require_in_range!(value, 0..=100, None);
+///     Some(value)
+/// }
+///
+/// assert_eq!(percent(0), Some(0));
+/// assert_eq!(percent(100), Some(100));
+/// assert_eq!(percent(-1), None);
+/// assert_eq!(percent(101), None);
+///
+/// fn index(i: usize, len: usize) -> bool {
+///     require_in_range!(i, ..len, false);
+///     true
+/// }
+///
+/// assert!(index(2, 3));
+/// assert!(!index(3, 3));
+///
+/// let mut adults = Vec::new();
+/// let mut admit = |name: String, age: u32| {
+///     require_in_range!(age, 18..);
+///     adults.push(name);
+/// };
+/// admit("kid".to_string(), 17);
+/// admit("adult".to_string(), 18);
+/// assert_eq!(adults, vec!["adult"]);
+/// ```
+macro_rules! require_in_range {
+    ($value: expr, $range: expr) => {
+        require!(::std::ops::RangeBounds::contains(&$range, &$value))
+    };
+    ($value: expr, $range: expr, $return: expr) => {
+        require!(::std::ops::RangeBounds::contains(&$range, &$value), $return)
     };
 }
@@ -0,0 +1,104 @@
+use crate::{WantExtract, WantExtractRef, WhatIwant};
+use std::error::Error;
+
+/// How bad an error is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
+
+/// Implement `HasSeverity` to let us know how bad your error is
+pub trait HasSeverity: Error {
+    fn severity(&self) -> Severity;
+}
+
+/// A `Result` whose errors are still what you want, up to a threshold severity
+///
+/// The tolerated errors are kept, so getting what you want out of it gives
+/// back the `Result`
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+/// use what_i_want::*;
+///
+/// #[derive(Debug)]
+/// struct SeverityError(Severity);
+///
+/// impl fmt::Display for SeverityError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{:?}", self.0)
+///     }
+/// }
+///
+/// impl std::error::Error for SeverityError {}
+///
+/// impl HasSeverity for SeverityError {
+///     fn severity(&self) -> Severity {
+///         self.0
+///     }
+/// }
+///
+/// fn process(threshold: Severity) -> Vec<Result<i32, SeverityError>> {
+///     let results = vec![
+///         Ok(1),
+///         Err(SeverityError(Severity::Warning)),
+///         Err(SeverityError(Severity::Error)),
+///     ];
+///     let mut processed = Vec::new();
+///     for result in results {
+///         processed.push(unwrap_or_continue!(SeverityWant::new(result, threshold)));
+///     }
+///     processed
+/// }
+///
+/// assert_eq!(process(Severity::Info).len(), 1);
+/// assert_eq!(process(Severity::Warning).len(), 2);
+/// assert_eq!(process(Severity::Fatal).len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct SeverityWant<T, E> {
+    result: Result<T, E>,
+    threshold: Severity,
+}
+
+impl<T, E: HasSeverity> SeverityWant<T, E> {
+    pub fn new(result: Result<T, E>, threshold: Severity) -> Self {
+        SeverityWant { result, threshold }
+    }
+}
+
+impl<T, E: HasSeverity> WhatIwant for SeverityWant<T, E> {
+    fn is_i_want(&self) -> bool {
+        match &self.result {
+            Ok(_) => true,
+            Err(e) => e.severity() <= self.threshold,
+        }
+    }
+}
+
+impl<T, E: HasSeverity> WantExtract for SeverityWant<T, E> {
+    type Output = Result<T, E>;
+    type Rejected = E;
+
+    fn ok_or_reject(self) -> Result<Result<T, E>, E> {
+        match self.result {
+            Err(e) if e.severity() > self.threshold => Err(e),
+            result => Ok(result),
+        }
+    }
+}
+
+impl<T, E: HasSeverity> WantExtractRef for SeverityWant<T, E> {
+    fn wanted_ref(&self) -> Option<&Result<T, E>> {
+        if self.is_i_want() {
+            Some(&self.result)
+        } else {
+            None
+        }
+    }
+}
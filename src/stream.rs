@@ -0,0 +1,69 @@
+use crate::WantExtract;
+use futures_core::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Stream returned by [`WantStreamExt::skip_rejected`]
+#[derive(Debug, Clone)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SkipRejected<S> {
+    stream: S,
+}
+
+impl<S> Stream for SkipRejected<S>
+where
+    S: Stream,
+    S::Item: WantExtract,
+{
+    type Item = <S::Item as WantExtract>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `stream` is structurally pinned, it's never moved out of
+        // `SkipRejected` and `SkipRejected` has no `Drop` impl
+        let mut stream = unsafe { self.map_unchecked_mut(|s| &mut s.stream) };
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if let Ok(want) = item.ok_or_reject() {
+                        return Poll::Ready(Some(want));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+/// Stream over what you want
+pub trait WantStreamExt: Stream + Sized {
+    /// Skip the items that are not what you want, and get what you want out of the rest
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use what_i_want::*;
+    ///
+    /// let items = stream::iter(vec![Ok(1), Err("bad"), Ok(2), Err("worse"), Ok(3)]);
+    /// let wanted: Vec<i32> = block_on(items.skip_rejected().collect());
+    /// assert_eq!(wanted, vec![1, 2, 3]);
+    ///
+    /// let items = stream::iter(vec![None, Some('a'), None, Some('b')]);
+    /// let wanted: Vec<char> = block_on(items.skip_rejected().collect());
+    /// assert_eq!(wanted, vec!['a', 'b']);
+    /// ```
+    fn skip_rejected(self) -> SkipRejected<Self>
+    where
+        Self::Item: WantExtract,
+    {
+        SkipRejected { stream: self }
+    }
+}
+
+impl<S: Stream> WantStreamExt for S {}
@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Counts how many times something fired within the current one-second window
+#[doc(hidden)]
+pub struct Throttle {
+    window: Mutex<Option<(Instant, u32)>>,
+}
+
+impl Throttle {
+    pub const fn new() -> Self {
+        Throttle {
+            window: Mutex::new(None),
+        }
+    }
+
+    /// Returns `true` if it may fire now, at most `max_per_second` times per second
+    pub fn allow(&self, max_per_second: u32) -> bool {
+        let mut window = match self.window.lock() {
+            Ok(window) => window,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let now = Instant::now();
+        match &mut *window {
+            Some((start, fired)) if now.duration_since(*start) < Duration::from_secs(1) => {
+                if *fired < max_per_second {
+                    *fired += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => {
+                *window = Some((now, 1));
+                max_per_second > 0
+            }
+        }
+    }
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{WantExtract, WantExtractRef, WhatIwant};
+use std::ops::{ControlFlow, Try};
+
+/// Anything that works with `?` is what you want when it would not return early
+///
+/// A blanket impl for every `Try` type would overlap the `Result` and `Option`
+/// impls, so the value is wrapped instead
+///
+/// # Examples
+///
+/// ```
+/// #![feature(try_trait_v2)]
+/// use std::convert::Infallible;
+/// use std::ops::{ControlFlow, FromResidual, Try};
+/// use what_i_want::*;
+///
+/// enum Reading {
+///     Value(i32),
+///     Offline,
+/// }
+///
+/// impl FromResidual<Option<Infallible>> for Reading {
+///     fn from_residual(_: Option<Infallible>) -> Self {
+///         Reading::Offline
+///     }
+/// }
+///
+/// impl Try for Reading {
+///     type Output = i32;
+///     type Residual = Option<Infallible>;
+///
+///     fn from_output(value: i32) -> Self {
+///         Reading::Value(value)
+///     }
+///
+///     fn branch(self) -> ControlFlow<Option<Infallible>, i32> {
+///         match self {
+///             Reading::Value(value) => ControlFlow::Continue(value),
+///             Reading::Offline => ControlFlow::Break(None),
+///         }
+///     }
+/// }
+///
+/// let readings = vec![Reading::Value(1), Reading::Offline, Reading::Value(2)];
+/// let mut values = Vec::new();
+/// for reading in readings {
+///     values.push(unwrap_or_continue!(TryWant::new(reading)));
+/// }
+/// assert_eq!(values, vec![1, 2]);
+/// ```
+pub struct TryWant<T: Try>(ControlFlow<T::Residual, T::Output>);
+
+impl<T: Try> TryWant<T> {
+    pub fn new(value: T) -> Self {
+        TryWant(value.branch())
+    }
+}
+
+impl<T: Try> WhatIwant for TryWant<T> {
+    fn is_i_want(&self) -> bool {
+        matches!(self.0, ControlFlow::Continue(_))
+    }
+}
+
+impl<T: Try> WantExtract for TryWant<T> {
+    type Output = T::Output;
+    type Rejected = T::Residual;
+
+    fn ok_or_reject(self) -> Result<T::Output, T::Residual> {
+        match self.0 {
+            ControlFlow::Continue(output) => Ok(output),
+            ControlFlow::Break(residual) => Err(residual),
+        }
+    }
+}
+
+impl<T: Try> WantExtractRef for TryWant<T> {
+    fn wanted_ref(&self) -> Option<&T::Output> {
+        match &self.0 {
+            ControlFlow::Continue(output) => Some(output),
+            ControlFlow::Break(_) => None,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::WantExtract;
+
+/// Implement `WantAnyhow` to turn what was rejected into an `anyhow::Error`
+///
+/// The default message is the [`reject_reason`](crate::WhatIwant::reject_reason),
+/// or `value not present`; `Result` keeps its own error
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// struct Empty;
+///
+/// impl WhatIwant for Empty {
+///     fn is_i_want(&self) -> bool {
+///         false
+///     }
+///
+///     fn reject_reason(&self) -> Option<String> {
+///         Some("it's empty".to_string())
+///     }
+/// }
+///
+/// impl WantExtract for Empty {
+///     type Output = ();
+///     type Rejected = ();
+///
+///     fn ok_or_reject(self) -> Result<(), ()> {
+///         Err(())
+///     }
+/// }
+///
+/// impl WantAnyhow for Empty {}
+///
+/// assert_eq!(Empty.ok_or_anyhow().unwrap_err().to_string(), "it's empty");
+/// assert_eq!(None::<i32>.ok_or_anyhow().unwrap_err().to_string(), "value not present");
+/// ```
+pub trait WantAnyhow: WantExtract + Sized {
+    fn ok_or_anyhow(self) -> anyhow::Result<Self::Output> {
+        let reason = if self.is_i_want() {
+            None
+        } else {
+            self.reject_reason()
+        };
+        self.ok_or_reject().map_err(|_| match reason {
+            Some(reason) => anyhow::Error::msg(reason),
+            None => anyhow::Error::msg("value not present"),
+        })
+    }
+}
+
+impl<T, E> WantAnyhow for Result<T, E>
+where
+    E: Into<anyhow::Error>,
+{
+    fn ok_or_anyhow(self) -> anyhow::Result<T> {
+        self.map_err(Into::into)
+    }
+}
+
+impl<T> WantAnyhow for Option<T> {}
@@ -0,0 +1,110 @@
+use crate::{WantExtract, WantExtractRef, WhatIwant};
+use either::Either;
+
+/// A `Right` is what you want, as in `either`'s own `From<Result>`, where
+/// `Ok` becomes `Right`
+///
+/// Use [`want_left`] to want the `Left` instead
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use what_i_want::*;
+///
+/// let sides: Vec<Either<&str, i32>> =
+///     vec![Either::Right(1), Either::Left("skip"), Either::Right(3)];
+/// let mut rights = Vec::new();
+/// for side in sides {
+///     rights.push(unwrap_or_continue!(side));
+/// }
+/// assert_eq!(rights, vec![1, 3]);
+/// assert_eq!(Either::<&str, i32>::Left("bad").ok_or_reject(), Err("bad"));
+/// ```
+impl<L, R> WhatIwant for Either<L, R> {
+    fn is_i_want(&self) -> bool {
+        self.is_right()
+    }
+}
+
+impl<L, R> WantExtract for Either<L, R> {
+    type Output = R;
+    type Rejected = L;
+
+    fn ok_or_reject(self) -> Result<R, L> {
+        self.either(Err, Ok)
+    }
+}
+
+impl<L, R> WantExtractRef for Either<L, R> {
+    fn wanted_ref(&self) -> Option<&R> {
+        self.as_ref().right()
+    }
+}
+
+/// An `Either` where the `Left` is what you want
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use what_i_want::*;
+///
+/// let sides: Vec<Either<i32, &str>> =
+///     vec![Either::Left(1), Either::Right("skip"), Either::Left(3)];
+/// let mut lefts = Vec::new();
+/// for side in sides {
+///     lefts.push(unwrap_or_continue!(want_left(side)));
+/// }
+/// assert_eq!(lefts, vec![1, 3]);
+/// assert_eq!(want_left(Either::<i32, &str>::Right("bad")).ok_or_reject(), Err("bad"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WantLeft<L, R>(pub Either<L, R>);
+
+/// Want the `Left` of the `Either`
+pub fn want_left<L, R>(either: Either<L, R>) -> WantLeft<L, R> {
+    WantLeft(either)
+}
+
+impl<L, R> WhatIwant for WantLeft<L, R> {
+    fn is_i_want(&self) -> bool {
+        self.0.is_left()
+    }
+}
+
+impl<L, R> WantExtract for WantLeft<L, R> {
+    type Output = L;
+    type Rejected = R;
+
+    fn ok_or_reject(self) -> Result<L, R> {
+        self.0.either(Ok, Err)
+    }
+}
+
+impl<L, R> WantExtractRef for WantLeft<L, R> {
+    fn wanted_ref(&self) -> Option<&L> {
+        self.0.as_ref().left()
+    }
+}
+
+/// Split it into what was rejected on the `Left` and what you want on the
+/// `Right`, the way [`Either`]'s `From<Result>` does
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use what_i_want::*;
+///
+/// assert_eq!(split_either(Some(1)), Either::Right(1));
+/// assert_eq!(split_either(None::<i32>), Either::Left(()));
+/// let rejected = split_either("x".parse::<u8>()).left().unwrap();
+/// assert_eq!(rejected.to_string(), "invalid digit found in string");
+/// ```
+pub fn split_either<W: WantExtract>(want: W) -> Either<W::Rejected, W::Output> {
+    match want.ok_or_reject() {
+        Ok(output) => Either::Right(output),
+        Err(rejected) => Either::Left(rejected),
+    }
+}
@@ -0,0 +1,1207 @@
+use crate::{WantExtract, WantExtractRef, WhatIwant};
+use std::any::Any;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::num::FpCategory;
+use std::ops::{ControlFlow, RangeBounds};
+use std::str::{FromStr, Utf8Error};
+use std::sync::TryLockResult;
+use std::thread;
+
+/// A `ControlFlow` where `Break` is what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use what_i_want::*;
+///
+/// let flows = vec![
+///     ControlFlow::Continue(()),
+///     ControlFlow::Break("found"),
+///     ControlFlow::Continue(()),
+/// ];
+/// let mut found = Vec::new();
+/// for cf in flows {
+///     found.push(unwrap_or_continue!(OnBreak(cf)));
+/// }
+/// assert_eq!(found, vec!["found"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnBreak<B, C>(pub ControlFlow<B, C>);
+
+impl<B, C> WhatIwant for OnBreak<B, C> {
+    fn is_i_want(&self) -> bool {
+        matches!(self.0, ControlFlow::Break(_))
+    }
+}
+
+impl<B, C> WantExtract for OnBreak<B, C> {
+    type Output = B;
+    type Rejected = C;
+
+    fn ok_or_reject(self) -> Result<B, C> {
+        match self.0 {
+            ControlFlow::Break(b) => Ok(b),
+            ControlFlow::Continue(c) => Err(c),
+        }
+    }
+}
+
+impl<B, C> WantExtractRef for OnBreak<B, C> {
+    fn wanted_ref(&self) -> Option<&B> {
+        match &self.0 {
+            ControlFlow::Break(b) => Some(b),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+}
+
+/// An item out of an async stream, where `None` is the end of the stream
+///
+/// It's just an `Option`, named for what it means in
+/// `while let Some(item) = stream.next().await`, see
+/// [`unwrap_or_break!`](crate::unwrap_or_break)
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use what_i_want::*;
+///
+/// let (seen, ended) = block_on(async {
+///     let mut items = stream::iter(vec!["a", "b"]);
+///     let mut seen = Vec::new();
+///     let mut ended = false;
+///     loop {
+///         let next = AsyncStreamItem(items.next().await);
+///         if !next.is_i_want() {
+///             ended = true;
+///         }
+///         seen.push(unwrap_or_break!(next));
+///     }
+///     (seen, ended)
+/// });
+/// assert_eq!(seen, vec!["a", "b"]);
+/// assert!(ended);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsyncStreamItem<T>(pub Option<T>);
+
+impl<T> WhatIwant for AsyncStreamItem<T> {
+    fn is_i_want(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl<T> WantExtract for AsyncStreamItem<T> {
+    type Output = T;
+    type Rejected = ();
+
+    fn ok_or_reject(self) -> Result<T, ()> {
+        self.0.ok_or(())
+    }
+}
+
+impl<T> WantExtractRef for AsyncStreamItem<T> {
+    fn wanted_ref(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+}
+
+/// An `Option` where only a value other than the default is what you want
+///
+/// For when `Some(0)` or `Some("")` means as little as `None`; what was
+/// rejected is the `Option` as it was
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert!(NonDefaultOption(Some(1)).is_i_want());
+/// assert!(!NonDefaultOption(Some(0i32)).is_i_want());
+/// assert!(!NonDefaultOption(None::<i32>).is_i_want());
+/// assert_eq!(NonDefaultOption(Some("")).ok_or_reject(), Err(Some("")));
+///
+/// let mut names = Vec::new();
+/// for name in vec![Some("admin"), Some(""), None, Some("root")] {
+///     names.push(unwrap_or_continue!(NonDefaultOption(name)));
+/// }
+/// assert_eq!(names, vec!["admin", "root"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonDefaultOption<T>(pub Option<T>);
+
+impl<T: Default + PartialEq> WhatIwant for NonDefaultOption<T> {
+    fn is_i_want(&self) -> bool {
+        self.0.as_ref().map(|v| v != &T::default()).unwrap_or(false)
+    }
+}
+
+impl<T: Default + PartialEq> WantExtract for NonDefaultOption<T> {
+    type Output = T;
+    type Rejected = Option<T>;
+
+    fn ok_or_reject(self) -> Result<T, Option<T>> {
+        if self.is_i_want() {
+            self.0.ok_or(None)
+        } else {
+            Err(self.0)
+        }
+    }
+}
+
+impl<T: Default + PartialEq> WantExtractRef for NonDefaultOption<T> {
+    fn wanted_ref(&self) -> Option<&T> {
+        if self.is_i_want() {
+            self.0.as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+/// What you want is what it does not want
+///
+/// Getting what you want out of it gives what was rejected, and the other way
+/// around
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert!(!not_want(Some(1)).is_i_want());
+/// assert!(not_want(None::<i32>).is_i_want());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Negated<T>(pub T);
+
+/// Negate what you want
+pub fn not_want<T: WhatIwant>(value: T) -> Negated<T> {
+    Negated(value)
+}
+
+impl<T: WhatIwant> WhatIwant for Negated<T> {
+    fn is_i_want(&self) -> bool {
+        !self.0.is_i_want()
+    }
+}
+
+impl<T: WantExtract> WantExtract for Negated<T> {
+    type Output = T::Rejected;
+    type Rejected = T::Output;
+
+    fn ok_or_reject(self) -> Result<T::Rejected, T::Output> {
+        match self.0.ok_or_reject() {
+            Ok(output) => Err(output),
+            Err(rejected) => Ok(rejected),
+        }
+    }
+}
+
+/// What you want is both of them being what you want
+///
+/// Getting what you want out of it gives both of what you want; when either
+/// is rejected, it gives back what came out of each of them
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(want_and!(Some(1), Ok::<_, ()>('a')).ok_or_reject().ok(), Some((1, 'a')));
+/// assert!(!want_and!(Some(1), None::<char>).is_i_want());
+/// assert!(!want_and!(None::<i32>, Some('a')).is_i_want());
+/// assert!(!want_and!(None::<i32>, None::<char>).is_i_want());
+///
+/// let rejected = want_and(Some(1), Err::<char, _>("bad")).ok_or_reject().unwrap_err();
+/// assert_eq!(rejected, (Ok(1), Err("bad")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WantAnd<A, B>(pub A, pub B);
+
+/// Want both of them, zipping them into one that is what you want only when
+/// both are
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let mut pairs = Vec::new();
+/// for (a, b) in vec![(Some(1), Some('a')), (Some(2), None), (None, Some('c'))] {
+///     pairs.push(unwrap_or_continue!(want_and(a, b)));
+/// }
+/// assert_eq!(pairs, vec![(1, 'a')]);
+///
+/// let zipped = zip_wanted("4".parse::<u8>(), Some("four"));
+/// assert_eq!(zipped.ok_or_reject().ok(), Some((4, "four")));
+/// let zipped = zip_wanted("x".parse::<u8>(), Some("four"));
+/// assert!(!zipped.is_i_want());
+/// ```
+pub fn want_and<A: WhatIwant, B: WhatIwant>(a: A, b: B) -> WantAnd<A, B> {
+    WantAnd(a, b)
+}
+
+impl<A: WhatIwant, B: WhatIwant> WhatIwant for WantAnd<A, B> {
+    fn is_i_want(&self) -> bool {
+        self.0.is_i_want() && self.1.is_i_want()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        if !self.0.is_i_want() {
+            self.0.reject_reason()
+        } else {
+            self.1.reject_reason()
+        }
+    }
+}
+
+impl<A: WantExtract, B: WantExtract> WantExtract for WantAnd<A, B> {
+    type Output = (A::Output, B::Output);
+    type Rejected = (
+        Result<A::Output, A::Rejected>,
+        Result<B::Output, B::Rejected>,
+    );
+
+    fn ok_or_reject(self) -> Result<Self::Output, Self::Rejected> {
+        match (self.0.ok_or_reject(), self.1.ok_or_reject()) {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            rejected => Err(rejected),
+        }
+    }
+}
+
+/// What you want is either of them being what you want
+///
+/// Getting what you want out of it gives what you want out of the first one
+/// that is what you want; when both are rejected, it gives back both of what
+/// was rejected
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(want_or!(Some(1), Some(2)).ok_or_reject(), Ok(1));
+/// assert_eq!(want_or!(Some(1), None).ok_or_reject(), Ok(1));
+/// assert_eq!(want_or!(None, Some(2)).ok_or_reject(), Ok(2));
+/// assert_eq!(want_or!(None::<i32>, None).ok_or_reject(), Err(((), ())));
+///
+/// let cached: Result<i32, &str> = Err("cache miss");
+/// assert_eq!(want_or(cached, "2".parse::<i32>().map_err(|_| "bad")).wanted_ref(), Some(&2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WantOr<A, B>(pub A, pub B);
+
+/// Want either of them
+pub fn want_or<A: WhatIwant, B: WhatIwant>(a: A, b: B) -> WantOr<A, B> {
+    WantOr(a, b)
+}
+
+impl<A: WhatIwant, B: WhatIwant> WhatIwant for WantOr<A, B> {
+    fn is_i_want(&self) -> bool {
+        self.0.is_i_want() || self.1.is_i_want()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        match (self.0.reject_reason(), self.1.reject_reason()) {
+            (Some(a), Some(b)) => Some(format!("{}, and {}", a, b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl<A, B> WantExtract for WantOr<A, B>
+where
+    A: WantExtract,
+    B: WantExtract<Output = A::Output>,
+{
+    type Output = A::Output;
+    type Rejected = (A::Rejected, B::Rejected);
+
+    fn ok_or_reject(self) -> Result<A::Output, (A::Rejected, B::Rejected)> {
+        match self.0.ok_or_reject() {
+            Ok(a) => Ok(a),
+            Err(a) => match self.1.ok_or_reject() {
+                Ok(b) => Ok(b),
+                Err(b) => Err((a, b)),
+            },
+        }
+    }
+}
+
+impl<A, B> WantExtractRef for WantOr<A, B>
+where
+    A: WantExtractRef,
+    B: WantExtractRef<Output = A::Output>,
+{
+    fn wanted_ref(&self) -> Option<&A::Output> {
+        self.0.wanted_ref().or_else(|| self.1.wanted_ref())
+    }
+}
+
+/// An `Ordering` where the `wanted` one is what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// fn is_sorted_by(items: &[i32], want: fn(std::cmp::Ordering) -> WantOrdering) -> bool {
+///     for pair in items.windows(2) {
+///         require!(want(pair[0].cmp(&pair[1])).is_i_want(), false);
+///     }
+///     true
+/// }
+///
+/// assert!(is_sorted_by(&[1, 2, 3], WantOrdering::less));
+/// assert!(!is_sorted_by(&[1, 3, 2], WantOrdering::less));
+/// assert!(is_sorted_by(&[3, 2, 1], WantOrdering::greater));
+/// assert!(!is_sorted_by(&[3, 3, 1], WantOrdering::greater));
+/// assert!(is_sorted_by(&[2, 2, 2], WantOrdering::equal));
+/// assert!(!is_sorted_by(&[2, 2, 1], WantOrdering::equal));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WantOrdering {
+    pub inner: Ordering,
+    pub wanted: Ordering,
+}
+
+impl WantOrdering {
+    /// `Equal` is what you want
+    pub fn equal(inner: Ordering) -> Self {
+        WantOrdering {
+            inner,
+            wanted: Ordering::Equal,
+        }
+    }
+
+    /// `Less` is what you want
+    pub fn less(inner: Ordering) -> Self {
+        WantOrdering {
+            inner,
+            wanted: Ordering::Less,
+        }
+    }
+
+    /// `Greater` is what you want
+    pub fn greater(inner: Ordering) -> Self {
+        WantOrdering {
+            inner,
+            wanted: Ordering::Greater,
+        }
+    }
+}
+
+impl WhatIwant for WantOrdering {
+    fn is_i_want(&self) -> bool {
+        self.inner == self.wanted
+    }
+}
+
+impl WantExtract for WantOrdering {
+    type Output = Ordering;
+    type Rejected = Ordering;
+
+    fn ok_or_reject(self) -> Result<Ordering, Ordering> {
+        if self.is_i_want() {
+            Ok(self.inner)
+        } else {
+            Err(self.inner)
+        }
+    }
+}
+
+impl WantExtractRef for WantOrdering {
+    fn wanted_ref(&self) -> Option<&Ordering> {
+        if self.is_i_want() {
+            Some(&self.inner)
+        } else {
+            None
+        }
+    }
+}
+
+/// A state machine state where the `expected` state is what you want
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Door {
+///     Open,
+///     Closed,
+///     Locked,
+/// }
+///
+/// assert!(CurrentState::new(Door::Open, Door::Open).is_i_want());
+/// assert!(!CurrentState::new(Door::Open, Door::Locked).is_i_want());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentState<S> {
+    pub current: S,
+    pub expected: S,
+}
+
+impl<S: PartialEq> CurrentState<S> {
+    pub fn new(current: S, expected: S) -> Self {
+        CurrentState { current, expected }
+    }
+}
+
+impl<S: PartialEq> WhatIwant for CurrentState<S> {
+    fn is_i_want(&self) -> bool {
+        self.current == self.expected
+    }
+}
+
+impl<S: PartialEq> WantExtract for CurrentState<S> {
+    type Output = S;
+    type Rejected = S;
+
+    fn ok_or_reject(self) -> Result<S, S> {
+        if self.is_i_want() {
+            Ok(self.current)
+        } else {
+            Err(self.current)
+        }
+    }
+}
+
+impl<S: PartialEq> WantExtractRef for CurrentState<S> {
+    fn wanted_ref(&self) -> Option<&S> {
+        if self.is_i_want() {
+            Some(&self.current)
+        } else {
+            None
+        }
+    }
+}
+
+/// A value inside the range is what you want
+///
+/// Any range works, including the half-open `..end` and `start..`; what was
+/// rejected is the value out of range
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert!(WantRangeCheck::new(0, 0..10).is_i_want());
+/// assert!(!WantRangeCheck::new(10, 0..10).is_i_want());
+/// assert!(WantRangeCheck::new(10, 0..=10).is_i_want());
+/// assert!(!WantRangeCheck::new(11, 0..=10).is_i_want());
+/// assert!(WantRangeCheck::new(-5, ..0).is_i_want());
+/// assert!(!WantRangeCheck::new(0, ..0).is_i_want());
+/// assert!(WantRangeCheck::new(0, ..=0).is_i_want());
+/// assert!(!WantRangeCheck::new(1, ..=0).is_i_want());
+/// assert!(WantRangeCheck::new(1.5, 1.5..).is_i_want());
+/// assert!(!WantRangeCheck::new(1.4, 1.5..).is_i_want());
+/// assert!(WantRangeCheck::new(i64::MIN, ..).is_i_want());
+///
+/// let mut scores = Vec::new();
+/// for score in vec![-1, 0, 50, 100, 101] {
+///     scores.push(unwrap_or_continue!(WantRangeCheck::new(score, 0..=100)));
+/// }
+/// assert_eq!(scores, vec![0, 50, 100]);
+/// assert_eq!(WantRangeCheck::new(7, ..5).ok_or_reject(), Err(7));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WantRangeCheck<T, R> {
+    pub value: T,
+    pub range: R,
+}
+
+impl<T: PartialOrd, R: RangeBounds<T>> WantRangeCheck<T, R> {
+    pub fn new(value: T, range: R) -> Self {
+        WantRangeCheck { value, range }
+    }
+}
+
+impl<T: PartialOrd, R: RangeBounds<T>> WhatIwant for WantRangeCheck<T, R> {
+    fn is_i_want(&self) -> bool {
+        self.range.contains(&self.value)
+    }
+}
+
+impl<T: PartialOrd, R: RangeBounds<T>> WantExtract for WantRangeCheck<T, R> {
+    type Output = T;
+    type Rejected = T;
+
+    fn ok_or_reject(self) -> Result<T, T> {
+        if self.is_i_want() {
+            Ok(self.value)
+        } else {
+            Err(self.value)
+        }
+    }
+}
+
+impl<T: PartialOrd, R: RangeBounds<T>> WantExtractRef for WantRangeCheck<T, R> {
+    fn wanted_ref(&self) -> Option<&T> {
+        if self.is_i_want() {
+            Some(&self.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// A float category where the `wanted` one is what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::num::FpCategory;
+/// use what_i_want::*;
+///
+/// let categories = [
+///     FpCategory::Nan,
+///     FpCategory::Infinite,
+///     FpCategory::Zero,
+///     FpCategory::Subnormal,
+///     FpCategory::Normal,
+/// ];
+/// for &wanted in &categories {
+///     for &category in &categories {
+///         let want = WantFpCategory::new(category, wanted);
+///         assert_eq!(want.is_i_want(), category == wanted);
+///     }
+/// }
+///
+/// let mut zeros = 0;
+/// for value in vec![0.0, 1.0, -0.0, f64::NAN] {
+///     unwrap_or_continue!(WantFpCategory::new(f64::classify(value), FpCategory::Zero));
+///     zeros += 1;
+/// }
+/// assert_eq!(zeros, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WantFpCategory {
+    pub category: FpCategory,
+    pub wanted: FpCategory,
+}
+
+impl WantFpCategory {
+    pub fn new(category: FpCategory, wanted: FpCategory) -> Self {
+        WantFpCategory { category, wanted }
+    }
+}
+
+impl WhatIwant for WantFpCategory {
+    fn is_i_want(&self) -> bool {
+        self.category == self.wanted
+    }
+}
+
+impl WantExtract for WantFpCategory {
+    type Output = FpCategory;
+    type Rejected = FpCategory;
+
+    fn ok_or_reject(self) -> Result<FpCategory, FpCategory> {
+        if self.is_i_want() {
+            Ok(self.category)
+        } else {
+            Err(self.category)
+        }
+    }
+}
+
+impl WantExtractRef for WantFpCategory {
+    fn wanted_ref(&self) -> Option<&FpCategory> {
+        if self.is_i_want() {
+            Some(&self.category)
+        } else {
+            None
+        }
+    }
+}
+
+/// An error slot where no error is what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use what_i_want::*;
+///
+/// fn step(fail: bool) -> NoError {
+///     if fail {
+///         NoError(Some("step failed".into()))
+///     } else {
+///         NoError(None)
+///     }
+/// }
+///
+/// fn pipeline(steps: &[bool]) -> Result<usize, Box<dyn Error>> {
+///     let mut done = 0;
+///     for &fail in steps {
+///         context_unwrap_or_return!(step(fail), |e| e);
+///         done += 1;
+///     }
+///     Ok(done)
+/// }
+///
+/// assert!(NoError(None).is_i_want());
+/// assert_eq!(pipeline(&[false, false]).unwrap(), 2);
+/// assert_eq!(pipeline(&[false, true, false]).unwrap_err().to_string(), "step failed");
+/// ```
+#[derive(Debug)]
+pub struct NoError(pub Option<Box<dyn Error>>);
+
+impl WhatIwant for NoError {
+    fn is_i_want(&self) -> bool {
+        self.0.is_none()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        self.0.as_ref().map(|e| e.to_string())
+    }
+}
+
+impl WantExtract for NoError {
+    type Output = ();
+    type Rejected = Box<dyn Error>;
+
+    fn ok_or_reject(self) -> Result<(), Box<dyn Error>> {
+        match self.0 {
+            None => Ok(()),
+            Some(e) => Err(e),
+        }
+    }
+}
+
+impl WantExtractRef for NoError {
+    fn wanted_ref(&self) -> Option<&()> {
+        if self.is_i_want() {
+            Some(&())
+        } else {
+            None
+        }
+    }
+}
+
+/// A `fmt::Write` writer whose last write succeeding is what you want
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::{self, Write};
+/// use what_i_want::*;
+///
+/// struct Limited {
+///     written: String,
+///     limit: usize,
+/// }
+///
+/// impl Write for Limited {
+///     fn write_str(&mut self, s: &str) -> fmt::Result {
+///         if self.written.len() + s.len() > self.limit {
+///             return Err(fmt::Error);
+///         }
+///         self.written.push_str(s);
+///         Ok(())
+///     }
+/// }
+///
+/// fn greet(writer: &mut WantWriter<Limited>) -> bool {
+///     unwrap_or_false!(writer.write_str("hello"));
+///     unwrap_or_false!(writer.write_str(", world"));
+///     true
+/// }
+///
+/// let mut roomy = WantWriter::new(Limited { written: String::new(), limit: 64 });
+/// assert!(greet(&mut roomy));
+/// assert!(roomy.is_i_want());
+/// assert_eq!(unwrap_or_val!(roomy, ()).written, "hello, world");
+///
+/// let mut cramped = WantWriter::new(Limited { written: String::new(), limit: 8 });
+/// assert!(!greet(&mut cramped));
+/// assert!(!cramped.is_i_want());
+/// assert_eq!(cramped.ok_or_reject().err(), Some(std::fmt::Error));
+/// ```
+#[derive(Debug)]
+pub struct WantWriter<W> {
+    writer: W,
+    last: fmt::Result,
+}
+
+impl<W: fmt::Write> WantWriter<W> {
+    pub fn new(writer: W) -> Self {
+        WantWriter {
+            writer,
+            last: Ok(()),
+        }
+    }
+
+    /// Get the writer back, whatever the last write did
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for WantWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.last = self.writer.write_str(s);
+        self.last
+    }
+}
+
+impl<W> WhatIwant for WantWriter<W> {
+    fn is_i_want(&self) -> bool {
+        self.last.is_ok()
+    }
+}
+
+impl<W> WantExtract for WantWriter<W> {
+    type Output = W;
+    type Rejected = fmt::Error;
+
+    fn ok_or_reject(self) -> Result<W, fmt::Error> {
+        self.last.map(|()| self.writer)
+    }
+}
+
+impl<W> WantExtractRef for WantWriter<W> {
+    fn wanted_ref(&self) -> Option<&W> {
+        if self.is_i_want() {
+            Some(&self.writer)
+        } else {
+            None
+        }
+    }
+}
+
+/// A lock that was acquired without blocking is what you want
+///
+/// Wraps the outcome of any `try_lock`-like method, either an `Option` of the
+/// guard or a [`TryLockResult`](std::sync::TryLockResult), where a poisoned
+/// lock is not what you want either
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+/// use what_i_want::*;
+///
+/// let counter = Mutex::new(0);
+/// for _ in 0..3 {
+///     let mut guard = unwrap_or_continue!(TryLockWant::from(counter.try_lock()));
+///     *guard += 1;
+/// }
+/// assert_eq!(*counter.lock().unwrap(), 3);
+///
+/// let held = counter.lock().unwrap();
+/// assert!(!TryLockWant::from(counter.try_lock()).is_i_want());
+/// drop(held);
+///
+/// let cell = std::cell::RefCell::new(1);
+/// let borrowed = cell.borrow_mut();
+/// assert!(!TryLockWant::new(cell.try_borrow().ok()).is_i_want());
+/// drop(borrowed);
+/// assert!(TryLockWant::new(cell.try_borrow().ok()).is_i_want());
+/// ```
+#[derive(Debug)]
+pub struct TryLockWant<G> {
+    guard: Option<G>,
+}
+
+impl<G> TryLockWant<G> {
+    pub fn new(guard: Option<G>) -> Self {
+        TryLockWant { guard }
+    }
+}
+
+impl<G> From<TryLockResult<G>> for TryLockWant<G> {
+    fn from(result: TryLockResult<G>) -> Self {
+        TryLockWant::new(result.ok())
+    }
+}
+
+impl<G> WhatIwant for TryLockWant<G> {
+    fn is_i_want(&self) -> bool {
+        self.guard.is_some()
+    }
+}
+
+impl<G> WantExtract for TryLockWant<G> {
+    type Output = G;
+    type Rejected = ();
+
+    fn ok_or_reject(self) -> Result<G, ()> {
+        self.guard.ok_or(())
+    }
+}
+
+impl<G> WantExtractRef for TryLockWant<G> {
+    fn wanted_ref(&self) -> Option<&G> {
+        self.guard.as_ref()
+    }
+}
+
+/// Bytes that are valid UTF-8 are what you want
+///
+/// Bytes that are not can still be recovered, lossily: getting what you want
+/// out of them gives the lossy string, with `U+FFFD` in place of the invalid
+/// sequences, as what was rejected
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// let valid = RecoverableUtf8::new(b"hello");
+/// assert!(valid.is_i_want());
+/// assert_eq!(valid.wanted_ref(), Some(&"hello"));
+/// assert_eq!(valid.ok_or_reject(), Ok("hello"));
+///
+/// let invalid = RecoverableUtf8::new(b"hel\xFFlo");
+/// assert!(!invalid.is_i_want());
+/// assert_eq!(invalid.error().map(|e| e.valid_up_to()), Some(3));
+/// assert!(invalid.reject_reason().unwrap().contains("invalid utf-8"));
+/// assert_eq!(invalid.lossy(), "hel\u{FFFD}lo");
+/// assert_eq!(invalid.ok_or_reject().unwrap_err(), "hel\u{FFFD}lo");
+///
+/// let mut strict = Vec::new();
+/// let mut recovered = Vec::new();
+/// for bytes in &[&b"ok"[..], &b"\xC3\x28"[..]] {
+///     let text = RecoverableUtf8::new(bytes);
+///     match text.ok_or_reject() {
+///         Ok(s) => strict.push(s.to_string()),
+///         Err(lossy) => recovered.push(lossy.into_owned()),
+///     }
+/// }
+/// assert_eq!(strict, vec!["ok"]);
+/// assert_eq!(recovered, vec!["\u{FFFD}("]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoverableUtf8<'a> {
+    bytes: &'a [u8],
+    checked: Result<&'a str, Utf8Error>,
+}
+
+impl<'a> RecoverableUtf8<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        RecoverableUtf8 {
+            bytes,
+            checked: std::str::from_utf8(bytes),
+        }
+    }
+
+    /// Why the bytes are not valid UTF-8
+    pub fn error(&self) -> Option<Utf8Error> {
+        self.checked.err()
+    }
+
+    /// The string, recovered lossily if the bytes are not valid UTF-8
+    pub fn lossy(&self) -> Cow<'a, str> {
+        match self.checked {
+            Ok(s) => Cow::Borrowed(s),
+            Err(_) => String::from_utf8_lossy(self.bytes),
+        }
+    }
+}
+
+impl<'a> WhatIwant for RecoverableUtf8<'a> {
+    fn is_i_want(&self) -> bool {
+        self.checked.is_ok()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        self.error().map(|e| e.to_string())
+    }
+}
+
+impl<'a> WantExtract for RecoverableUtf8<'a> {
+    type Output = &'a str;
+    type Rejected = Cow<'a, str>;
+
+    fn ok_or_reject(self) -> Result<&'a str, Cow<'a, str>> {
+        match self.checked {
+            Ok(s) => Ok(s),
+            Err(_) => Err(self.lossy()),
+        }
+    }
+}
+
+impl<'a> WantExtractRef for RecoverableUtf8<'a> {
+    fn wanted_ref(&self) -> Option<&&'a str> {
+        self.checked.as_ref().ok()
+    }
+}
+
+/// A buffered reader or writer whose stream has no error is what you want
+///
+/// A reader is checked by filling its buffer with `from_reader`, so the first
+/// read error shows up right away; a writer is checked by flushing it with
+/// `from_writer`
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, BufRead, BufReader, BufWriter, Cursor, Read, Write};
+/// use what_i_want::*;
+///
+/// #[derive(Debug)]
+/// struct Broken;
+///
+/// impl Read for Broken {
+///     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+///         Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset"))
+///     }
+/// }
+///
+/// impl Write for Broken {
+///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+///         Err(io::Error::new(io::ErrorKind::BrokenPipe, "broken"))
+///     }
+///
+///     fn flush(&mut self) -> io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let reader = WantBufIo::from_reader(BufReader::new(Cursor::new(b"a\nb".to_vec())));
+/// assert!(reader.is_i_want());
+/// let lines: Vec<String> = reader.ok_or_reject().unwrap().lines().map(Result::unwrap).collect();
+/// assert_eq!(lines, vec!["a", "b"]);
+///
+/// let reader = WantBufIo::from_reader(BufReader::new(Broken));
+/// assert!(!reader.is_i_want());
+/// assert_eq!(reader.reject_reason().as_deref(), Some("reset"));
+/// assert_eq!(reader.ok_or_reject().unwrap_err().kind(), io::ErrorKind::ConnectionReset);
+///
+/// let mut writer = BufWriter::new(Vec::new());
+/// writer.write_all(b"hello").unwrap();
+/// let writer = WantBufIo::from_writer(writer);
+/// assert_eq!(writer.wanted_ref().map(|w| w.get_ref().len()), Some(5));
+///
+/// let mut writer = BufWriter::new(Broken);
+/// writer.write_all(b"hello").unwrap();
+/// assert!(!WantBufIo::from_writer(writer).is_i_want());
+/// ```
+#[derive(Debug)]
+pub struct WantBufIo<B> {
+    buf: B,
+    error: Option<io::Error>,
+}
+
+impl<R: Read> WantBufIo<BufReader<R>> {
+    /// Fill the reader's buffer, keeping the error if reading failed
+    pub fn from_reader(mut reader: BufReader<R>) -> Self {
+        let error = reader.fill_buf().err();
+        WantBufIo { buf: reader, error }
+    }
+}
+
+impl<W: Write> WantBufIo<BufWriter<W>> {
+    /// Flush the writer's buffer, keeping the error if writing failed
+    pub fn from_writer(mut writer: BufWriter<W>) -> Self {
+        let error = writer.flush().err();
+        WantBufIo { buf: writer, error }
+    }
+}
+
+impl<B> WhatIwant for WantBufIo<B> {
+    fn is_i_want(&self) -> bool {
+        self.error.is_none()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        self.error.as_ref().map(|e| e.to_string())
+    }
+}
+
+impl<B> WantExtract for WantBufIo<B> {
+    type Output = B;
+    type Rejected = io::Error;
+
+    fn ok_or_reject(self) -> Result<B, io::Error> {
+        match self.error {
+            None => Ok(self.buf),
+            Some(e) => Err(e),
+        }
+    }
+}
+
+impl<B> WantExtractRef for WantBufIo<B> {
+    fn wanted_ref(&self) -> Option<&B> {
+        if self.error.is_none() {
+            Some(&self.buf)
+        } else {
+            None
+        }
+    }
+}
+
+/// A string that parses is what you want
+///
+/// Any `FromStr` type can be parsed, whatever its error is; the error is what
+/// was rejected, so the logging macros describe it with `Display` when it has
+/// one
+///
+/// # Examples
+///
+/// ```
+/// use what_i_want::*;
+///
+/// assert_eq!(WantParsed::<i32>::new("42").ok_or_reject(), Ok(42));
+/// assert!(!WantParsed::<i32>::new("4 2").is_i_want());
+/// assert_eq!(
+///     WantParsed::<u8>::new("300").ok_or_reject().unwrap_err().to_string(),
+///     "number too large to fit in target type"
+/// );
+///
+/// let inputs = vec!["1", "x", "2", "3.5", "4"];
+/// let mut sum = 0;
+/// for s in &inputs {
+///     sum += unwrap_or_continue!(WantParsed::<i32>::new(s));
+/// }
+/// assert_eq!(sum, 7);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Flag(bool);
+///
+/// impl std::str::FromStr for Flag {
+///     type Err = ();
+///
+///     fn from_str(s: &str) -> Result<Flag, ()> {
+///         match s {
+///             "on" => Ok(Flag(true)),
+///             "off" => Ok(Flag(false)),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(WantParsed::<Flag>::new("on").ok_or_reject(), Ok(Flag(true)));
+/// assert!(!WantParsed::<Flag>::new("maybe").is_i_want());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WantParsed<T: FromStr>(pub Result<T, T::Err>);
+
+impl<T: FromStr> WantParsed<T> {
+    pub fn new(s: &str) -> Self {
+        WantParsed(s.parse())
+    }
+}
+
+impl<T: FromStr> WhatIwant for WantParsed<T> {
+    fn is_i_want(&self) -> bool {
+        self.0.is_ok()
+    }
+}
+
+impl<T: FromStr> WantExtract for WantParsed<T> {
+    type Output = T;
+    type Rejected = T::Err;
+
+    fn ok_or_reject(self) -> Result<T, T::Err> {
+        self.0
+    }
+}
+
+impl<T: FromStr> WantExtractRef for WantParsed<T> {
+    fn wanted_ref(&self) -> Option<&T> {
+        self.0.as_ref().ok()
+    }
+}
+
+/// A thread that completed without panicking is what you want
+///
+/// Whether a thread panicked is only known once it's joined, so `new` joins it
+/// and keeps the result; `is_i_want` and `ok_or_reject` both look at that
+/// result and always agree
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use what_i_want::*;
+///
+/// let handles: Vec<thread::JoinHandle<i32>> = (0..6)
+///     .map(|i| {
+///         thread::spawn(move || {
+///             if i % 2 == 1 {
+///                 panic!("thread {} failed", i);
+///             }
+///             i
+///         })
+///     })
+///     .collect();
+///
+/// let mut results = Vec::new();
+/// let mut reasons = Vec::new();
+/// for handle in handles {
+///     let joined = WantJoined::new(handle);
+///     if let Some(reason) = joined.reject_reason() {
+///         reasons.push(reason);
+///     }
+///     results.push(unwrap_or_continue!(joined));
+/// }
+/// assert_eq!(results, vec![0, 2, 4]);
+/// assert_eq!(reasons[0], "the thread panicked: thread 1 failed");
+///
+/// test_conformance!(
+///     WantJoined::new(thread::spawn(|| 1)),
+///     WantJoined::new(thread::spawn(|| -> i32 { panic!("failed") }))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct WantJoined<T>(pub thread::Result<T>);
+
+impl<T> WantJoined<T> {
+    /// Join the thread, blocking until it's finished
+    pub fn new(handle: thread::JoinHandle<T>) -> Self {
+        WantJoined(handle.join())
+    }
+}
+
+impl<T> From<thread::JoinHandle<T>> for WantJoined<T> {
+    fn from(handle: thread::JoinHandle<T>) -> Self {
+        WantJoined::new(handle)
+    }
+}
+
+impl<T> WhatIwant for WantJoined<T> {
+    fn is_i_want(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    fn reject_reason(&self) -> Option<String> {
+        let payload = self.0.as_ref().err()?;
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+        Some(match message {
+            Some(message) => format!("the thread panicked: {}", message),
+            None => "the thread panicked".to_string(),
+        })
+    }
+}
+
+impl<T> WantExtract for WantJoined<T> {
+    type Output = T;
+    type Rejected = Box<dyn Any + Send + 'static>;
+
+    fn ok_or_reject(self) -> thread::Result<T> {
+        self.0
+    }
+}
+
+impl<T> WantExtractRef for WantJoined<T> {
+    fn wanted_ref(&self) -> Option<&T> {
+        self.0.as_ref().ok()
+    }
+}